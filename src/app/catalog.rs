@@ -0,0 +1,46 @@
+// src/app/catalog.rs
+// A small bundled catalog of approximate download sizes for well-known large models.
+// Ollama does not report a model's size before it is pulled, so this is used to warn
+// before pulling models that are known to be big (see the "confirm large pulls" setting).
+
+use crate::app::utils::with_default_tag;
+
+const GB: u64 = 1_000_000_000; // Sizes as listed on ollama.com (decimal gigabytes)
+
+/// Approximate download sizes of well-known large models (name:tag, bytes).
+/// Not exhaustive: models missing here are simply not checked.
+pub const KNOWN_MODEL_SIZES: &[(&str, u64)] = &[
+    ("llama3:70b", 40 * GB),
+    ("llama3.1:70b", 43 * GB),
+    ("llama3.1:405b", 243 * GB),
+    ("llama3.3:70b", 43 * GB),
+    ("llama3.3:latest", 43 * GB),
+    ("qwen2.5:72b", 47 * GB),
+    ("mixtral:8x7b", 26 * GB),
+    ("mixtral:latest", 26 * GB),
+    ("mixtral:8x22b", 80 * GB),
+    ("command-r-plus:latest", 59 * GB),
+    ("deepseek-r1:70b", 43 * GB),
+    ("deepseek-r1:671b", 404 * GB),
+    ("gemma2:27b", 16 * GB),
+];
+
+/// Returns the approximate size of a model from the bundled catalog, if it is listed.
+/// Names without a tag are looked up as `name:latest`.
+pub fn known_model_size(model_id: &str) -> Option<u64> {
+    let name = with_default_tag(&model_id.trim().to_lowercase());
+    KNOWN_MODEL_SIZES
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, size)| *size)
+}
+
+/// Returns the models (with their catalog size) that are at least `threshold_gib` large.
+pub fn large_models(model_ids: &[String], threshold_gib: u64) -> Vec<(String, u64)> {
+    let threshold_bytes = threshold_gib.saturating_mul(1024 * 1024 * 1024);
+    model_ids
+        .iter()
+        .filter_map(|id| known_model_size(id).map(|size| (id.clone(), size)))
+        .filter(|(_, size)| *size >= threshold_bytes)
+        .collect()
+}
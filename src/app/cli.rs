@@ -0,0 +1,210 @@
+// src/app/cli.rs
+// Headless command line mode: `llamalift pull <model>...` pulls models without opening a window, and
+// `llamalift pull -` reads the model names from stdin (one per line), e.g. to pipe a list from another tool.
+// Uses the saved settings (host, retries, redirects, default namespace, aliases) and the same pull code
+// as the Download view.
+
+use crate::app::{
+    config::{self, AppSettings, Config, BACKOFF_MS_RANGE, MAX_RETRIES_LIMIT},
+    ollama,
+    state::UpdateMessage,
+    ui::views::download_view::{resolve_model_input, split_duplicates},
+    utils::validate_model_identifier,
+};
+use chrono_tz::Tz;
+use log::{error, info, warn};
+use std::{
+    io::{self, BufRead},
+    str::FromStr,
+    sync::{
+        mpsc::{Receiver, Sender},
+        Arc,
+    },
+};
+
+/// Exit code when every model was pulled.
+pub const EXIT_OK: i32 = 0;
+/// Exit code when at least one model failed to pull.
+pub const EXIT_PULL_FAILED: i32 = 1;
+/// Exit code for usage errors (no or invalid model names, unreadable stdin); nothing was pulled.
+pub const EXIT_USAGE: i32 = 2;
+
+const USAGE: &str = "Usage: llamalift pull <model>... | llamalift pull -   (reads one model per line from stdin)";
+
+/// Returns true if the command line asks for a headless command instead of the GUI.
+pub fn is_headless_command(args: &[String]) -> bool {
+    args.first().is_some_and(|command| command == "pull")
+}
+
+/// Runs a headless command (see `is_headless_command`) and returns the process exit code.
+/// `args` are the command line arguments without the program name. Progress goes to stderr,
+/// one result line per model to stdout.
+pub fn run_headless(args: &[String], sender: Sender<UpdateMessage>, receiver: Receiver<UpdateMessage>) -> i32 {
+    #[cfg(windows)]
+    attach_parent_console();
+    let models = match collect_model_args(&args[1..], io::stdin().lock()) {
+        Ok(models) if !models.is_empty() => models,
+        Ok(_) => {
+            eprintln!("No models given.\n{}", USAGE);
+            return EXIT_USAGE;
+        }
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return EXIT_USAGE;
+        }
+    };
+
+    let settings = config::load_settings(config::read_only_config_requested()).unwrap_or_else(|e| {
+        warn!("Failed to load config file, using defaults: {}", e);
+        AppSettings::default()
+    });
+    let models: Vec<String> = models
+        .iter()
+        .map(|input| {
+            let resolved = resolve_model_input(input, &settings);
+            if resolved != *input {
+                info!("Resolved '{}' to '{}'.", input, resolved);
+            }
+            resolved
+        })
+        .collect();
+    let invalid_models: Vec<String> = models
+        .iter()
+        .filter_map(|id| validate_model_identifier(id).err())
+        .collect();
+    if !invalid_models.is_empty() {
+        eprintln!("Invalid model identifier(s): {}", invalid_models.join("; "));
+        return EXIT_USAGE;
+    }
+    let (models, duplicates) = split_duplicates(models);
+    for duplicate in &duplicates {
+        warn!("Skipping '{}': listed more than once.", duplicate);
+    }
+
+    // Show the pull status (not the log lines, the logger already prints those) on stderr
+    std::thread::spawn(move || {
+        let mut last_status_text = String::new();
+        for msg in receiver {
+            if let UpdateMessage::StatusText(text) = msg {
+                if text != last_status_text {
+                    eprintln!("  {}", text);
+                    last_status_text = text;
+                }
+            }
+        }
+    });
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            error!("Failed to start the async runtime: {}", e);
+            return EXIT_PULL_FAILED;
+        }
+    };
+    let config = headless_config(&settings);
+    info!("Pulling {} model(s) from {}.", models.len(), config.ollama_host);
+
+    let mut failed = 0;
+    for (index, model) in models.iter().enumerate() {
+        eprintln!("[{}/{}] Pulling {}...", index + 1, models.len(), model);
+        match runtime.block_on(ollama::pull_model_async(model, &config, sender.clone(), Arc::default())) {
+            Ok(()) => println!("ok\t{}", model),
+            Err(e) => {
+                error!("Failed to pull {}: {}", model, e);
+                println!("failed\t{}\t{}", model, e);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        eprintln!("{} of {} model(s) failed.", failed, models.len());
+        EXIT_PULL_FAILED
+    } else {
+        eprintln!("All {} model(s) pulled.", models.len());
+        EXIT_OK
+    }
+}
+
+// Release builds on Windows use the GUI subsystem and have no console of their own, so the progress and
+// result lines would go nowhere. Attaches to the console of the shell that started the process, if any.
+#[cfg(windows)]
+fn attach_parent_console() {
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX; // (DWORD)-1
+    // SAFETY: takes no pointers; if there is no parent console it fails and the output stays unattached
+    if unsafe { AttachConsole(ATTACH_PARENT_PROCESS) } == 0 {
+        warn!("No parent console to attach to, headless output is not shown.");
+    }
+}
+
+// Returns the model arguments, with `-` replaced by the lines of `stdin` (trimmed; blank lines and
+// `#` comments are skipped).
+fn collect_model_args(args: &[String], stdin: impl BufRead) -> Result<Vec<String>, String> {
+    let mut models = Vec::new();
+    let mut stdin = Some(stdin);
+    for arg in args {
+        if arg != "-" {
+            models.push(arg.trim().to_string());
+            continue;
+        }
+        let Some(input) = stdin.take() else {
+            return Err("'-' (stdin) can only be given once.".to_string());
+        };
+        for line in input.lines() {
+            let line = line.map_err(|e| format!("Failed to read model names from stdin: {}", e))?;
+            let line = line.trim();
+            if !line.is_empty() && !line.starts_with('#') {
+                models.push(line.to_string());
+            }
+        }
+    }
+    Ok(models.into_iter().filter(|model| !model.is_empty()).collect())
+}
+
+// Builds the runtime configuration from the saved settings, like the GUI does.
+fn headless_config(settings: &AppSettings) -> Config {
+    Config {
+        ollama_host: settings.ollama_host.clone(),
+        host_generation: 0,
+        tz: Tz::from_str(&settings.tz).unwrap_or(Tz::UTC),
+        stream_pulls: settings.stream_pulls,
+        max_redirects: settings.max_redirects,
+        follow_pull_redirects: settings.follow_pull_redirects,
+        max_retries: settings.max_retries.min(MAX_RETRIES_LIMIT),
+        base_backoff_ms: settings
+            .base_backoff_ms
+            .clamp(*BACKOFF_MS_RANGE.start(), *BACKOFF_MS_RANGE.end()),
+        custom_headers: settings.custom_headers.clone(),
+        cli_list_fallback: settings.cli_list_fallback,
+        disk_guard: config::disk_guard(settings, config::detect_models_dir().as_deref()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn stdin_lines_replace_the_dash_without_blank_or_comment_lines() {
+        let stdin = "llama3\n\n   \n# embeddings\n  nomic-embed-text  \n#mistral\n";
+        assert_eq!(
+            collect_model_args(&args(&["phi3", "-", "qwen2"]), stdin.as_bytes()).unwrap(),
+            ["phi3", "llama3", "nomic-embed-text", "qwen2"]
+        );
+        assert!(collect_model_args(&args(&["-"]), "# nothing\n\n".as_bytes()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn dash_can_only_be_given_once() {
+        let result = collect_model_args(&args(&["-", "llama3", "-"]), "mistral\n".as_bytes());
+        assert!(result.unwrap_err().contains("only be given once"));
+    }
+}
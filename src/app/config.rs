@@ -3,12 +3,23 @@
 
 // Import necessary types from sibling modules
 use crate::app::state::{ColumnState, ModelColumn, SortState};
+use crate::app::utils::{is_local_host, redact_url_credentials};
 
+use chrono::{DateTime, Utc};
 use chrono_tz::Tz;
 use dotenvy::dotenv;
-use log::{warn, LevelFilter}; // Use log::warn for consistency
+use log::{info, warn, LevelFilter}; // Use log::warn for consistency
 use serde::{Deserialize, Serialize};
-use std::{env, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    env,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
 
 // --- Global Configuration Block ---
 pub const SCRIPT_VERSION: &str = "0.1.1";
@@ -17,6 +28,39 @@ pub const MAX_MODEL_INPUTS: usize = 100;
 pub const DEFAULT_TZ: &str = "Europe/Vienna";
 pub const DEFAULT_LOG_LEVEL: &str = "INFO";
 pub const DEFAULT_OLLAMA_HOST: &str = "127.0.0.1:11434";
+pub const DEFAULT_VISIBLE_COLUMNS_ENV: &str = "DEFAULT_VISIBLE_COLUMNS";
+pub const READ_ONLY_CONFIG_ENV: &str = "READ_ONLY_CONFIG";
+pub const PS_POLL_INTERVAL_SECS: u64 = 30; // How often /api/ps is polled for last-used tracking
+pub const REFRESH_DEBOUNCE_MS: u64 = 1000; // Refresh clicks within this window are ignored
+pub const BATCH_PROGRESS_CLEAR_DELAY_SECS: u64 = 5; // Delay before a finished batch's progress is hidden
+pub const WARM_MODEL_KEEP_ALIVE: &str = "10m"; // How long a manually loaded model stays in memory
+pub const MAX_DETAIL_PREFETCHES: usize = 2; // Concurrent background /api/show requests for visible rows
+pub const MAX_RETRIES_LIMIT: u32 = 10; // Upper bound for the "max retries" setting
+pub const BACKOFF_MS_RANGE: std::ops::RangeInclusive<u64> = 100..=30_000; // Bounds for the "base backoff" setting
+
+// --- Log Panel Verbosity ---
+
+/// Most verbose level that reaches the log panel, shared with the logger in main.rs.
+/// Set from `AppSettings::gui_log_level` on startup and whenever the settings change.
+static GUI_LOG_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Info as usize);
+
+/// Sets the log panel level from a level name (`INFO`, `debug`, ...); invalid names fall back to INFO.
+pub fn set_gui_log_level(level: &str) {
+    let level = LevelFilter::from_str(level).unwrap_or(LevelFilter::Info);
+    GUI_LOG_LEVEL.store(level as usize, Ordering::Relaxed);
+}
+
+/// Returns the most verbose level that reaches the log panel.
+pub fn gui_log_level() -> LevelFilter {
+    match GUI_LOG_LEVEL.load(Ordering::Relaxed) {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Error,
+        2 => LevelFilter::Warn,
+        3 => LevelFilter::Info,
+        4 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
 
 // --- Configuration Structs ---
 
@@ -24,7 +68,21 @@ pub const DEFAULT_OLLAMA_HOST: &str = "127.0.0.1:11434";
 #[derive(Clone, Debug)]
 pub struct Config {
     pub ollama_host: String,
+    /// Host generation this config was taken under (see `OllamaPullerApp::sync_host_generation`).
+    /// Results tagged with an older generation come from a replaced host and are ignored.
+    pub host_generation: u64,
     pub tz: Tz,
+    pub stream_pulls: bool,
+    pub max_redirects: usize,
+    pub follow_pull_redirects: bool,
+    pub max_retries: u32,
+    pub base_backoff_ms: u64,
+    /// Extra headers sent with every request to the Ollama server (see `AppSettings::custom_headers`).
+    pub custom_headers: Vec<(String, String)>,
+    /// Local model store and the free space (bytes) pulls must leave on its volume, see `disk_guard`.
+    pub disk_guard: Option<(PathBuf, u64)>,
+    /// List models with the `ollama list` CLI when the API is unreachable (see `AppSettings::cli_list_fallback`).
+    pub cli_list_fallback: bool,
 }
 
 /// Configuration loaded initially from environment/.env for logger setup and defaults (Made pub)
@@ -33,10 +91,11 @@ pub struct InitialConfig {
     pub ollama_host: String,
     pub log_level: LevelFilter,
     pub tz: Tz,
+    pub models_dir: Option<PathBuf>, // Local Ollama model store, if found
 }
 
 /// Persistently stored application settings using confy (Made pub)
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(default)]
 pub struct AppSettings {
     pub ollama_host: String,
@@ -45,25 +104,188 @@ pub struct AppSettings {
     #[serde(default = "default_column_states")] 
     pub model_column_states: Vec<ColumnState>,
     pub model_sort_state: SortState,
+    /// Whether the Manage view groups tags under their base model name.
+    pub model_group_by_base: bool,
+    /// Keep the Manage view filters (e.g. "Only loaded") across restarts.
+    pub remember_filters: bool,
+    /// Whether the Manage view lists only loaded models. Only kept if `remember_filters` is set.
+    pub show_only_loaded: bool,
+    /// Last time each model was seen loaded via `/api/ps` (app-maintained heuristic).
+    pub model_last_used: HashMap<String, DateTime<Utc>>,
+    /// Hide the progress bars a few seconds after a batch pull completes successfully.
+    pub auto_clear_batch_progress: bool,
+    /// Request streamed pull progress. Disable for proxies that break chunked responses.
+    pub stream_pulls: bool,
+    /// Play a chime when a batch pull finishes (requires the `sound` build feature).
+    pub play_sound_on_completion: bool,
+    /// After a successful batch pull, open the Prompt Workbench with the first pulled model ready to test.
+    pub test_after_download: bool,
+    /// Namespace prepended (as `namespace/name`) to entered model names that have none. Empty = off.
+    pub default_namespace: String,
+    /// Short names expanded to full model identifiers in the Download view (e.g. `code` -> `codellama:13b-instruct`).
+    pub model_aliases: BTreeMap<String, String>,
+    /// Alternate row backgrounds in the models table.
+    pub table_striped: bool,
+    /// Draw vertical gridlines between the models table columns.
+    pub table_gridlines: bool,
+    /// Wrap long cell values (e.g. Families) within the column width; rows grow to fit.
+    pub table_wrap_text: bool,
+    /// Fetch `/api/show` details for the visible rows of the models table in the background.
+    pub prefetch_model_details: bool,
+    /// After a delete or pull, update only the affected models' entries instead of reloading the whole list.
+    pub per_model_refresh: bool,
+    /// Update the model list when a batch pull finishes, so the Manage view shows the new models.
+    pub refresh_after_pull: bool,
+    /// Background color of the models table header (None = theme default).
+    pub table_header_color: Option<[u8; 3]>,
+    /// Background color of striped rows in the models table (None = theme default).
+    pub table_stripe_color: Option<[u8; 3]>,
+    /// Keep the Name column in place while the other columns of the models table scroll horizontally.
+    pub freeze_name_column: bool,
+    /// Ask before pulling models the bundled catalog lists as larger than the threshold.
+    pub confirm_large_pulls: bool,
+    /// Size threshold (GiB) for `confirm_large_pulls`.
+    pub large_pull_threshold_gib: u64,
+    /// Refuse or stop pulls that would leave less than `min_free_disk_gib` free in the local model store.
+    pub abort_on_low_disk: bool,
+    /// Free space (GiB) to keep on the model store's volume for `abort_on_low_disk`.
+    pub min_free_disk_gib: u64,
+    /// Ask before starting a batch with more than `batch_size_confirm_threshold` models.
+    pub confirm_batch_size: bool,
+    /// Batch size above which `confirm_batch_size` asks for confirmation.
+    pub batch_size_confirm_threshold: usize,
+    /// Ask before deleting a model. When off, the delete button deletes right away (a badge shows this).
+    pub confirm_deletes: bool,
+    /// Characters of the digest shown in the Digest column (0 = full digest).
+    pub digest_display_chars: usize,
+    /// Strip the `sha256:` (algorithm) prefix from displayed digests.
+    pub digest_strip_prefix: bool,
+    /// Show more digest characters when shortened digests of different models would look the same.
+    pub digest_disambiguate: bool,
+    /// Show "—" instead of "0 B" for models that report no size (e.g. some custom/blob models).
+    pub dash_for_unknown_size: bool,
+    /// Keep the LlamaLift window above other windows (not supported on every platform).
+    pub always_on_top: bool,
+    /// Show the newest log lines at the top of the log view.
+    pub logs_newest_first: bool,
+    /// Expand the log panel (and scroll to the newest lines) when an operation fails.
+    pub expand_logs_on_error: bool,
+    /// Most verbose level shown in the log panel, independent of `log_level` (file/terminal output).
+    pub gui_log_level: String,
+    /// Maximum HTTP redirects followed per request (0 = don't follow redirects).
+    pub max_redirects: usize,
+    /// Also follow redirects for pulls. Off by default, as streamed pulls tend to break across redirects.
+    pub follow_pull_redirects: bool,
+    /// Retries for requests failing with a connection error, timeout or 502/503/504 (0 = no retries).
+    pub max_retries: u32,
+    /// Delay before the first retry in milliseconds, doubled for each further retry.
+    pub base_backoff_ms: u64,
+    /// Extra HTTP headers (name, value) sent with every request to the Ollama server, e.g. `X-Api-Key`
+    /// or a tenant ID for a proxy in front of it.
+    pub custom_headers: Vec<(String, String)>,
+    /// When the Ollama API is unreachable, list the models with the `ollama list` CLI instead (needs the
+    /// CLI installed; for unusual local setups where the CLI reaches the server but the API URL doesn't).
+    pub cli_list_fallback: bool,
+    /// Override for the local model store directory (empty = `OLLAMA_MODELS` or the platform default).
+    pub models_dir: String,
+    /// Check GitHub at most daily for a newer LlamaLift release. Off by default for privacy.
+    pub check_for_updates: bool,
+    /// Ask registry.ollama.ai at most daily whether the installed tags have newer versions. Off by default for privacy.
+    pub check_model_updates: bool,
+    /// Serve the local remote-control API (127.0.0.1 only) for automation scripts.
+    pub remote_api_enabled: bool,
+    /// Port of the remote-control API.
+    pub remote_api_port: u16,
+    /// Pause background polling after this many minutes without focus or input (0 = never pause).
+    pub idle_pause_minutes: u64,
+    /// When the last successful update check happened.
+    pub last_update_check: Option<DateTime<Utc>>,
+    /// Latest release found by the update check: version and release page URL.
+    pub latest_release: Option<(String, String)>,
 }
 
 // --- Default Implementation for AppSettings ---
 
 /// Provides default column states (visibility and order). Made public.
+/// The initially visible set can be overridden with `DEFAULT_VISIBLE_COLUMNS`
+/// (comma-separated, e.g. `Name,Size,Family,QuantizationLevel`); otherwise Name/Size/Modified.
 pub fn default_column_states() -> Vec<ColumnState> {
+    let visible_override = default_visible_columns_override();
     ModelColumn::all()
         .into_iter()
         .map(|col| ColumnState {
-            visible: matches!(
-                col,
-                ModelColumn::Name | ModelColumn::Size | ModelColumn::Modified
-            ),
+            visible: match &visible_override {
+                Some(visible) => visible.contains(&col),
+                None => matches!(
+                    col,
+                    ModelColumn::Name | ModelColumn::Size | ModelColumn::Modified
+                ),
+            },
             column: col,
             width: None,
         })
         .collect()
 }
 
+/// Ensures the saved column states match the available columns.
+/// This handles cases where new columns are added to the app but are not yet in the saved config.
+/// Returns true if the states were changed (and should be re-saved).
+pub fn reconcile_column_states(settings: &mut AppSettings) -> bool {
+    let all_cols_enum: HashSet<ModelColumn> = ModelColumn::all().into_iter().collect();
+    let current_cols_enum: HashSet<ModelColumn> = settings
+        .model_column_states
+        .iter()
+        .map(|cs| cs.column.clone())
+        .collect();
+
+    if all_cols_enum == current_cols_enum {
+        return false;
+    }
+    warn!("Mismatch between available columns and saved column states. Updating configuration.");
+    let mut new_states = Vec::new();
+
+    // Keep existing states and add new ones with default visibility/width
+    // Ensure the order from default_states is maintained
+    for default_state in default_column_states() {
+        if let Some(existing_state) = settings
+            .model_column_states
+            .iter()
+            .find(|cs| cs.column == default_state.column)
+        {
+            new_states.push(existing_state.clone());
+        } else {
+            info!("Adding new column state for: {:?}", default_state.column);
+            new_states.push(default_state);
+        }
+    }
+    settings.model_column_states = new_states;
+    true
+}
+
+/// Reads the `DEFAULT_VISIBLE_COLUMNS` env/.env override for the first-run column layout.
+/// Returns None if unset or if it contains no valid column names.
+fn default_visible_columns_override() -> Option<Vec<ModelColumn>> {
+    let value = env::var(DEFAULT_VISIBLE_COLUMNS_ENV).ok()?;
+    let mut columns = Vec::new();
+    for key in value.split(',').map(str::trim).filter(|k| !k.is_empty()) {
+        match ModelColumn::from_key(key) {
+            Some(col) => columns.push(col),
+            None => warn!(
+                "Unknown column '{}' in {}, ignoring it.",
+                key, DEFAULT_VISIBLE_COLUMNS_ENV
+            ),
+        }
+    }
+    if columns.is_empty() {
+        warn!(
+            "{} is set but contains no valid columns, using the built-in default.",
+            DEFAULT_VISIBLE_COLUMNS_ENV
+        );
+        return None;
+    }
+    Some(columns)
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         let initial_config = load_initial_config();
@@ -74,12 +296,83 @@ impl Default for AppSettings {
             // Use the specific default functions for table state
             model_column_states: default_column_states(),
             model_sort_state: SortState::default(),
+            model_group_by_base: false, // Flat table by default
+            remember_filters: false,
+            show_only_loaded: false,
+            model_last_used: HashMap::new(),
+            auto_clear_batch_progress: false,
+            stream_pulls: true,
+            play_sound_on_completion: false,
+            test_after_download: false,
+            default_namespace: String::new(),
+            model_aliases: BTreeMap::new(),
+            table_striped: true,
+            table_gridlines: false,
+            table_wrap_text: false,
+            freeze_name_column: false,
+            table_header_color: None,
+            table_stripe_color: None,
+            prefetch_model_details: false,
+            per_model_refresh: false,
+            refresh_after_pull: true,
+            confirm_large_pulls: false,
+            large_pull_threshold_gib: 20,
+            abort_on_low_disk: true,
+            min_free_disk_gib: 2,
+            confirm_batch_size: true,
+            confirm_deletes: true,
+            batch_size_confirm_threshold: 10,
+            digest_display_chars: 12,
+            digest_strip_prefix: false,
+            digest_disambiguate: true,
+            dash_for_unknown_size: true,
+            always_on_top: false,
+            logs_newest_first: false,
+            expand_logs_on_error: true,
+            gui_log_level: DEFAULT_LOG_LEVEL.to_string(),
+            max_redirects: 10,
+            follow_pull_redirects: false,
+            models_dir: String::new(),
+            max_retries: 3,
+            base_backoff_ms: 500,
+            custom_headers: Vec::new(),
+            cli_list_fallback: false,
+            check_for_updates: false,
+            check_model_updates: false,
+            remote_api_enabled: false,
+            remote_api_port: 11435,
+            idle_pause_minutes: 10,
+            last_update_check: None,
+            latest_release: None,
         }
     }
 }
 
 // --- Configuration Loading Functions ---
 
+/// Returns true if `READ_ONLY_CONFIG` (env/.env) asks LlamaLift never to write its config file,
+/// e.g. on immutable-filesystem deployments. Settings then live in memory only.
+pub fn read_only_config_requested() -> bool {
+    env::var(READ_ONLY_CONFIG_ENV)
+        .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+/// Loads the saved settings. `confy::load` stores the defaults when there is no config file yet, so
+/// with `read_only` (see `read_only_config_requested`) the file is only read if it exists, and a missing
+/// one gives the defaults without writing anything.
+pub fn load_settings(read_only: bool) -> Result<AppSettings, confy::ConfyError> {
+    if !read_only {
+        return confy::load(APP_NAME, None);
+    }
+    let path = confy::get_configuration_file_path(APP_NAME, None)?;
+    if !path.is_file() {
+        info!("No config file at {}, using the default settings (read-only config).", path.display());
+        return Ok(AppSettings::default());
+    }
+    confy::load_path(path)
+}
+
 /// Loads the *initial* configuration settings. (Made pub)
 /// Priority: Environment Variables > .env file > Hardcoded Defaults.
 /// This is primarily used for setting up the logger and providing defaults
@@ -129,9 +422,142 @@ pub fn load_initial_config() -> InitialConfig {
         DEFAULT_OLLAMA_HOST.to_string()
     });
 
+    // Locate the model store (OLLAMA_MODELS, then the platform defaults)
+    let models_dir = detect_models_dir();
+
     InitialConfig {
         ollama_host,
         log_level,
         tz,
+        models_dir,
+    }
+}
+
+/// The missing `OLLAMA_MODELS` directory last warned about, so detecting the store again doesn't repeat it.
+static MISSING_OLLAMA_MODELS: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Finds the local Ollama model store: `OLLAMA_MODELS` if set, otherwise the first existing
+/// platform default (`~/.ollama/models`, or `/usr/share/ollama/.ollama/models` for the Linux service install).
+/// Returns None if no existing directory is found (e.g. Ollama runs on another machine).
+/// Touches the filesystem, so the GUI keeps the result (`OllamaPullerApp::detected_models_dir`).
+pub fn detect_models_dir() -> Option<PathBuf> {
+    if let Ok(dir) = env::var("OLLAMA_MODELS") {
+        let path = PathBuf::from(dir.trim());
+        if path.is_dir() {
+            return Some(path);
+        }
+        let mut last_warned = MISSING_OLLAMA_MODELS.lock().unwrap();
+        if last_warned.as_ref() != Some(&path) {
+            warn!("OLLAMA_MODELS is set to '{}', but that directory does not exist.", path.display());
+            *last_warned = Some(path);
+        }
+    }
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"));
+    let mut candidates: Vec<PathBuf> = home
+        .map(|home| PathBuf::from(home).join(".ollama").join("models"))
+        .into_iter()
+        .collect();
+    if cfg!(target_os = "linux") {
+        candidates.push(PathBuf::from("/usr/share/ollama/.ollama/models"));
+    }
+    candidates.into_iter().find(|path| path.is_dir())
+}
+
+/// Environment variables LlamaLift reads (directly or through `.env`), shown in the effective configuration.
+const CONFIG_ENV_VARS: [&str; 4] = ["OLLAMA_HOST", "LOG_LEVEL", "TZ", "OLLAMA_MODELS"];
+
+/// Builds the diagnostic view of the configuration in effect (Help → Effective Configuration) as pretty JSON:
+/// the values used at runtime after fallbacks, the environment variables they may come from, and the saved
+/// settings. Credentials in the host URL are redacted.
+pub fn effective_config_json(settings: &AppSettings, config_path: Option<&Path>) -> String {
+    let tz = Tz::from_str(&settings.tz).map_or_else(|_| format!("UTC (invalid '{}')", settings.tz), |tz| tz.to_string());
+    let runtime = serde_json::json!({
+        "version": SCRIPT_VERSION,
+        "ollama_host": redact_url_credentials(&settings.ollama_host),
+        "tz": tz,
+        "gui_log_level": gui_log_level().to_string(),
+        "models_dir": resolve_models_dir(settings, detect_models_dir().as_deref()).map(|dir| dir.display().to_string()),
+        "config_file": config_path.map(|path| path.display().to_string()),
+    });
+    let environment: serde_json::Map<String, serde_json::Value> = CONFIG_ENV_VARS
+        .iter()
+        .map(|name| {
+            let value = env::var(name).ok().map(|value| redact_url_credentials(&value));
+            (name.to_string(), serde_json::json!(value))
+        })
+        .collect();
+    let mut saved = serde_json::to_value(settings).unwrap_or_else(|e| serde_json::json!(e.to_string()));
+    if let Some(host) = saved.get_mut("ollama_host") {
+        *host = serde_json::json!(redact_url_credentials(&settings.ollama_host));
+    }
+    // Header values are often API keys
+    if let Some(headers) = saved.get_mut("custom_headers") {
+        *headers = settings
+            .custom_headers
+            .iter()
+            .map(|(name, _)| serde_json::json!([name, "<redacted>"]))
+            .collect();
+    }
+    let effective = serde_json::json!({
+        "runtime": runtime,
+        "environment": environment,
+        "settings": saved,
+    });
+    serde_json::to_string_pretty(&effective).unwrap_or_else(|e| format!("Could not format the configuration: {}", e))
+}
+
+/// Returns true if a detected model store path comes from `OLLAMA_MODELS` (rather than a platform default).
+pub fn models_dir_from_env(path: &Path) -> bool {
+    env::var("OLLAMA_MODELS").is_ok_and(|dir| Path::new(dir.trim()) == path)
+}
+
+/// Checks a model store override: it must be an existing directory. Returns a warning if it doesn't
+/// look like an Ollama model store (no `blobs` and `manifests` folders), e.g. its parent directory.
+pub fn validate_models_dir(dir: &str) -> Result<Option<String>, String> {
+    let path = Path::new(dir.trim());
+    if !path.is_dir() {
+        return Err(format!("'{}' is not an existing directory", dir.trim()));
+    }
+    if !path.join("blobs").is_dir() || !path.join("manifests").is_dir() {
+        return Ok(Some("no blobs/manifests folders, this may not be an Ollama model store".to_string()));
+    }
+    Ok(None)
+}
+
+/// Returns the model store directory to use: the settings override if set and valid,
+/// otherwise `detected` (see `detect_models_dir`). An invalid override is ignored silently here
+/// (see `invalid_models_dir_override`, reported once at startup).
+pub fn resolve_models_dir(settings: &AppSettings, detected: Option<&Path>) -> Option<PathBuf> {
+    let override_dir = settings.models_dir.trim();
+    if !override_dir.is_empty() {
+        let path = PathBuf::from(override_dir);
+        if path.is_dir() {
+            return Some(path);
+        }
+    }
+    detected.map(Path::to_path_buf)
+}
+
+/// Returns a warning if the model store override is set but isn't an existing directory (e.g. it was
+/// removed, or the config file was edited by hand), in which case the detected store is used instead.
+pub fn invalid_models_dir_override(settings: &AppSettings) -> Option<String> {
+    let override_dir = settings.models_dir.trim();
+    if override_dir.is_empty() || Path::new(override_dir).is_dir() {
+        return None;
+    }
+    Some(format!(
+        "Model store override '{}' is not an existing directory, using the detected model store instead.",
+        override_dir
+    ))
+}
+
+/// Returns the model store to watch during pulls and the free space to keep there, if the low disk
+/// check is on and the Ollama host is this machine. A remote server stores pulls on its own disk,
+/// whose free space can't be checked from here, so the local store says nothing about it.
+pub fn disk_guard(settings: &AppSettings, detected_models_dir: Option<&Path>) -> Option<(PathBuf, u64)> {
+    if !settings.abort_on_low_disk || !is_local_host(&settings.ollama_host) {
+        return None;
     }
+    let min_free_bytes = settings.min_free_disk_gib.saturating_mul(1024 * 1024 * 1024);
+    resolve_models_dir(settings, detected_models_dir).map(|dir| (dir, min_free_bytes))
 }
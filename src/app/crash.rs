@@ -0,0 +1,80 @@
+// src/app/crash.rs
+// Local crash reporting: a panic hook that writes the panic message, a backtrace and the most recent
+// log lines to a crash file next to the config file. Nothing is sent anywhere.
+
+use crate::app::config::{APP_NAME, SCRIPT_VERSION};
+use chrono::Local;
+use std::{
+    backtrace::Backtrace,
+    collections::VecDeque,
+    env, fs,
+    panic,
+    path::PathBuf,
+    sync::Mutex,
+};
+
+// --- Recent Log Lines ---
+
+/// Number of recent log lines included in a crash report.
+const RECENT_LOG_CAPACITY: usize = 200;
+
+/// Ring buffer of the most recent log lines, filled by the logger in main.rs.
+static RECENT_LOG_LINES: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Remembers a formatted log line for a potential crash report.
+pub fn record_log_line(line: &str) {
+    if let Ok(mut lines) = RECENT_LOG_LINES.lock() {
+        if lines.len() >= RECENT_LOG_CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(line.to_string());
+    }
+}
+
+// --- Panic Hook ---
+
+/// Installs a panic hook that writes a crash report file, then runs the default hook.
+/// Only uses the filesystem, so it also works in release builds without a console (windows_subsystem).
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |panic_info| {
+        let report = format!(
+            "{} v{} crash report\nTime: {}\nOS: {} ({})\n\nPanic: {}\n\nBacktrace:\n{}\n\nRecent log lines:\n{}\n",
+            APP_NAME,
+            SCRIPT_VERSION,
+            Local::now().format("%Y-%m-%d %H:%M:%S %Z"),
+            env::consts::OS,
+            env::consts::ARCH,
+            panic_info,
+            Backtrace::force_capture(),
+            recent_log_lines(),
+        );
+        let path = crash_file_path();
+        match fs::write(&path, report) {
+            Ok(_) => eprintln!("{} crashed. A crash report was written to {}", APP_NAME, path.display()),
+            Err(e) => eprintln!("{} crashed, and writing the crash report to {} failed: {}", APP_NAME, path.display(), e),
+        }
+        default_hook(panic_info);
+    }));
+}
+
+/// Returns the recent log lines, one per line. Uses try_lock, as the panic may have
+/// happened while the buffer was locked.
+fn recent_log_lines() -> String {
+    match RECENT_LOG_LINES.try_lock() {
+        Ok(lines) => lines.iter().cloned().collect::<Vec<_>>().join("\n"),
+        Err(_) => "(unavailable)".to_string(),
+    }
+}
+
+/// Returns the crash file path: next to the config file if its directory is known,
+/// otherwise in the system temp directory.
+fn crash_file_path() -> PathBuf {
+    let file_name = format!("crash-{}.log", Local::now().format("%Y%m%d-%H%M%S"));
+    let dir = confy::get_configuration_file_path(APP_NAME, None)
+        .ok()
+        .and_then(|config_path| config_path.parent().map(PathBuf::from))
+        .filter(|dir| fs::create_dir_all(dir).is_ok())
+        .unwrap_or_else(env::temp_dir);
+    dir.join(file_name)
+}
@@ -0,0 +1,330 @@
+// src/app/maintenance.rs
+// Local model store maintenance: finds leftovers of interrupted pulls (partial downloads and blobs no
+// manifest references) and models whose blobs don't match their manifest, and works out how much
+// deleting each model would actually free (models share layers). Ollama's API can't report
+// these, so this reads the store directory directly; it only works when Ollama runs on this machine.
+//
+// Store layout: `manifests/<registry>/<namespace>/<model>/<tag>` (JSON listing the config and layer
+// digests with their sizes) and `blobs/sha256-<hash>` (in-progress pulls write `sha256-<hash>-partial*`).
+
+use log::{debug, info, warn};
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Registry and namespace Ollama leaves out of model names (`llama3:latest`).
+const DEFAULT_REGISTRY: &str = "registry.ollama.ai";
+const DEFAULT_NAMESPACE: &str = "library";
+
+#[derive(Deserialize)]
+struct Manifest {
+    config: Option<ManifestLayer>,
+    #[serde(default)]
+    layers: Vec<ManifestLayer>,
+}
+
+#[derive(Deserialize)]
+struct ManifestLayer {
+    digest: String,
+    size: u64,
+}
+
+/// A file in the blob directory.
+#[derive(Debug, Clone)]
+pub struct BlobFile {
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// A model whose blobs are missing or don't have the size its manifest expects.
+#[derive(Debug, Clone)]
+pub struct IncompleteModel {
+    pub name: String,
+    pub expected_size: u64,
+    pub on_disk_size: u64,
+    pub missing_blobs: usize,
+}
+
+/// The result of scanning the model store.
+#[derive(Debug, Clone, Default)]
+pub struct MaintenanceReport {
+    /// Partial downloads (`*-partial*`) left by interrupted pulls.
+    pub partial_files: Vec<BlobFile>,
+    /// Complete blobs that no manifest references (e.g. from deleted models or abandoned pulls).
+    pub unreferenced_blobs: Vec<BlobFile>,
+    pub incomplete_models: Vec<IncompleteModel>,
+    /// Manifests that could not be read, with the reason.
+    pub unreadable_manifests: Vec<String>,
+}
+
+impl MaintenanceReport {
+    /// The files a cleanup would delete (partial downloads and unreferenced blobs).
+    pub fn removable_files(&self) -> impl Iterator<Item = &BlobFile> {
+        self.partial_files.iter().chain(&self.unreferenced_blobs)
+    }
+
+    pub fn removable_size(&self) -> u64 {
+        self.removable_files().map(|f| f.size).sum()
+    }
+
+    /// The files of this (possibly stale) report that `fresh`, a scan taken right before deleting,
+    /// still finds removable. A blob a pull has finished meanwhile is referenced by now and is left alone.
+    pub fn still_removable(&self, fresh: &MaintenanceReport) -> Vec<BlobFile> {
+        let shown: HashSet<&Path> = self.removable_files().map(|f| f.path.as_path()).collect();
+        fresh.removable_files().filter(|f| shown.contains(f.path.as_path())).cloned().collect()
+    }
+}
+
+/// Scans the model store at `models_dir` for leftovers and incomplete models.
+pub fn scan_model_store(models_dir: &Path) -> Result<MaintenanceReport, String> {
+    let blobs_dir = models_dir.join("blobs");
+    let manifests_dir = models_dir.join("manifests");
+    if !blobs_dir.is_dir() || !manifests_dir.is_dir() {
+        return Err(format!(
+            "{} doesn't look like an Ollama model store (no blobs/manifests directories)",
+            models_dir.display()
+        ));
+    }
+
+    let mut report = MaintenanceReport::default();
+
+    // Blob sizes by file name
+    let mut blob_sizes: HashMap<String, u64> = HashMap::new();
+    let entries = fs::read_dir(&blobs_dir).map_err(|e| format!("Cannot read {}: {}", blobs_dir.display(), e))?;
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.metadata() else { continue };
+        if !metadata.is_file() {
+            continue;
+        }
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name.contains("-partial") {
+            report.partial_files.push(BlobFile { path: entry.path(), size: metadata.len() });
+        } else {
+            blob_sizes.insert(file_name, metadata.len());
+        }
+    }
+
+    // Check every manifest against the blobs
+    let mut referenced: HashSet<String> = HashSet::new();
+    let mut manifest_paths = Vec::new();
+    collect_files(&manifests_dir, &mut manifest_paths);
+    for path in manifest_paths {
+        let name = model_name_from_manifest_path(&manifests_dir, &path);
+        let manifest: Manifest = match fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()))
+        {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                warn!("Could not read the manifest of '{}': {}", name, e);
+                report.unreadable_manifests.push(format!("{}: {}", name, e));
+                continue;
+            }
+        };
+        let mut expected_size = 0;
+        let mut on_disk_size = 0;
+        let mut missing_blobs = 0;
+        for layer in manifest.config.iter().chain(&manifest.layers) {
+            let file_name = layer.digest.replace(':', "-");
+            expected_size += layer.size;
+            match blob_sizes.get(&file_name) {
+                Some(size) => on_disk_size += size,
+                None => missing_blobs += 1,
+            }
+            referenced.insert(file_name);
+        }
+        if missing_blobs > 0 || on_disk_size != expected_size {
+            debug!(
+                "Model '{}' is incomplete: {} of {} bytes on disk, {} blob(s) missing.",
+                name, on_disk_size, expected_size, missing_blobs
+            );
+            report.incomplete_models.push(IncompleteModel { name, expected_size, on_disk_size, missing_blobs });
+        }
+    }
+
+    // Never treat blobs as unreferenced if some manifest couldn't be read, it may reference them
+    if report.unreadable_manifests.is_empty() {
+        report.unreferenced_blobs = blob_sizes
+            .into_iter()
+            .filter(|(file_name, _)| file_name.starts_with("sha256-") && !referenced.contains(file_name))
+            .map(|(file_name, size)| BlobFile { path: blobs_dir.join(file_name), size })
+            .collect();
+    }
+    report.partial_files.sort_by(|a, b| a.path.cmp(&b.path));
+    report.unreferenced_blobs.sort_by(|a, b| a.path.cmp(&b.path));
+    report.incomplete_models.sort_by(|a, b| a.name.cmp(&b.name));
+
+    info!(
+        "Model store scan of {}: {} partial download(s), {} unreferenced blob(s), {} incomplete model(s).",
+        models_dir.display(),
+        report.partial_files.len(),
+        report.unreferenced_blobs.len(),
+        report.incomplete_models.len()
+    );
+    Ok(report)
+}
+
+/// Returns each model's unique footprint in the store at `models_dir`: the size of the blobs no other
+/// model references, i.e. what deleting only that model would free. Reported sizes count shared
+/// layers (e.g. the base model of a fine-tune) for every model using them. Reads all manifests, so
+/// it is only run on request. Fails if any manifest can't be read: the layers that model shares would
+/// look unique to the others, overstating what deleting them frees.
+pub fn unique_model_sizes(models_dir: &Path) -> Result<HashMap<String, u64>, String> {
+    let manifests_dir = models_dir.join("manifests");
+    if !manifests_dir.is_dir() {
+        return Err(format!("{} doesn't look like an Ollama model store (no manifests directory)", models_dir.display()));
+    }
+    let mut manifest_paths = Vec::new();
+    collect_files(&manifests_dir, &mut manifest_paths);
+
+    // Blobs (digest -> size) per model, and how many models use each blob
+    let mut model_blobs: Vec<(String, HashMap<String, u64>)> = Vec::new();
+    let mut blob_users: HashMap<String, usize> = HashMap::new();
+    let mut unreadable_manifests: Vec<String> = Vec::new();
+    for path in manifest_paths {
+        let name = model_name_from_manifest_path(&manifests_dir, &path);
+        let manifest: Manifest = match fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()))
+        {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                warn!("Could not read the manifest of '{}': {}", name, e);
+                unreadable_manifests.push(format!("{}: {}", name, e));
+                continue;
+            }
+        };
+        let blobs: HashMap<String, u64> = manifest
+            .config
+            .iter()
+            .chain(&manifest.layers)
+            .map(|layer| (layer.digest.clone(), layer.size))
+            .collect();
+        for digest in blobs.keys() {
+            *blob_users.entry(digest.clone()).or_default() += 1;
+        }
+        model_blobs.push((name, blobs));
+    }
+    if !unreadable_manifests.is_empty() {
+        return Err(format!(
+            "could not read the manifest(s) of {}, so shared layers can't be told apart",
+            unreadable_manifests.join("; ")
+        ));
+    }
+
+    let sizes: HashMap<String, u64> = model_blobs
+        .into_iter()
+        .map(|(name, blobs)| {
+            let unique_size = blobs
+                .iter()
+                .filter(|(digest, _)| blob_users.get(*digest) == Some(&1))
+                .map(|(_, size)| size)
+                .sum();
+            (name, unique_size)
+        })
+        .collect();
+    info!("Computed the unique disk footprint of {} model(s) in {}.", sizes.len(), models_dir.display());
+    Ok(sizes)
+}
+
+/// Deletes the given files, returning the number of bytes freed and an error message per failure.
+pub fn remove_files<'a>(files: impl Iterator<Item = &'a BlobFile>) -> (u64, Vec<String>) {
+    let mut freed = 0;
+    let mut errors = Vec::new();
+    for file in files {
+        match fs::remove_file(&file.path) {
+            Ok(()) => {
+                info!("Removed {}", file.path.display());
+                freed += file.size;
+            }
+            Err(e) => {
+                warn!("Could not remove {}: {}", file.path.display(), e);
+                errors.push(format!("{}: {}", file.path.display(), e));
+            }
+        }
+    }
+    (freed, errors)
+}
+
+// Collects all files below `dir` (manifests are nested by registry, namespace and model).
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+// Turns a manifest path back into the model name Ollama lists:
+// `registry.ollama.ai/library/llama3/latest` -> `llama3:latest`,
+// `registry.ollama.ai/team/model/q4` -> `team/model:q4`, `host/ns/model/tag` -> `host/ns/model:tag`.
+fn model_name_from_manifest_path(manifests_dir: &Path, path: &Path) -> String {
+    let parts: Vec<String> = path
+        .strip_prefix(manifests_dir)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect();
+    let Some((tag, repository)) = parts.split_last() else {
+        return path.display().to_string();
+    };
+    let repository = match repository {
+        [registry, namespace, rest @ ..] if registry == DEFAULT_REGISTRY && namespace == DEFAULT_NAMESPACE => rest.join("/"),
+        [registry, rest @ ..] if registry == DEFAULT_REGISTRY => rest.join("/"),
+        _ => repository.join("/"),
+    };
+    format!("{}:{}", repository, tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blob(file_name: &str, size: u64) -> BlobFile {
+        BlobFile { path: PathBuf::from("/models/blobs").join(file_name), size }
+    }
+
+    #[test]
+    fn unique_sizes_fail_when_a_manifest_is_unreadable() {
+        let models_dir = std::env::temp_dir().join(format!("llamalift-unique-sizes-{}", std::process::id()));
+        let library = models_dir.join("manifests").join(DEFAULT_REGISTRY).join(DEFAULT_NAMESPACE);
+        fs::create_dir_all(library.join("llama3")).unwrap();
+        fs::create_dir_all(library.join("llama3-tuned")).unwrap();
+        let manifest = r#"{"config":{"digest":"sha256:cfg","size":1},"layers":[{"digest":"sha256:base","size":100}]}"#;
+        fs::write(library.join("llama3").join("latest"), manifest).unwrap();
+
+        let sizes = unique_model_sizes(&models_dir).unwrap();
+        assert_eq!(sizes.get("llama3:latest"), Some(&101));
+
+        // Shares the base layer, but its manifest is cut off: llama3's base layer must not look unique
+        fs::write(library.join("llama3-tuned").join("latest"), &manifest[..40]).unwrap();
+        let result = unique_model_sizes(&models_dir);
+        fs::remove_dir_all(&models_dir).unwrap();
+        assert!(result.unwrap_err().contains("llama3-tuned:latest"));
+    }
+
+    #[test]
+    fn cleanup_skips_files_a_fresh_scan_no_longer_lists() {
+        // Scanned during a pull: the finished layer had no manifest yet
+        let stale = MaintenanceReport {
+            partial_files: vec![blob("sha256-bbb-partial", 10)],
+            unreferenced_blobs: vec![blob("sha256-aaa", 100), blob("sha256-old", 5)],
+            ..Default::default()
+        };
+        // After the pull: its manifest references the layer, and a new partial file appeared
+        let fresh = MaintenanceReport {
+            partial_files: vec![blob("sha256-ccc-partial", 20)],
+            unreferenced_blobs: vec![blob("sha256-old", 5)],
+            ..Default::default()
+        };
+        let paths: Vec<PathBuf> = stale.still_removable(&fresh).into_iter().map(|f| f.path).collect();
+        assert_eq!(paths, [PathBuf::from("/models/blobs/sha256-old")]);
+    }
+}
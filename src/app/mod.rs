@@ -2,10 +2,19 @@
 // Main application logic for LlamaLift. Defines the App struct, implements the eframe::App trait, and coordinates UI, state, configuration, and Ollama interactions, including persistent column widths and temporary settings state.
 
 // Declare sibling modules within the `app` module
+pub mod catalog;
+pub mod cli;
 pub mod config;
+pub mod crash;
+pub mod maintenance;
+pub mod model_updates;
 pub mod state;
 pub mod ollama;
+pub mod remote_api;
+#[cfg(feature = "sound")]
+pub mod sound;
 pub mod ui;
+pub mod update_check;
 pub mod utils;
 
 // Use necessary external crates
@@ -17,30 +26,56 @@ use eframe::{
     },
     App, CreationContext,
 };
-use log::{debug, error, info, warn};
+use log::{debug, error, info, trace, warn};
+use chrono::{DateTime, Utc};
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet, VecDeque},
     path::PathBuf,
     str::FromStr,
     sync::{
+        atomic::AtomicBool,
         mpsc::{Receiver, Sender},
         Arc,
         Mutex,
     },
+    time::{Duration, Instant},
 };
 use tokio::runtime::Runtime; // Async runtime
 
 // Use types defined in sibling modules
 use self::{
-    config::{AppSettings, Config, APP_NAME, SCRIPT_VERSION}, // Import AppSettings and Config
-    ollama::OllamaModel,
+    config::{
+        AppSettings, Config, APP_NAME, BACKOFF_MS_RANGE, BATCH_PROGRESS_CLEAR_DELAY_SECS,
+        MAX_DETAIL_PREFETCHES, MAX_RETRIES_LIMIT, PS_POLL_INTERVAL_SECS, SCRIPT_VERSION, WARM_MODEL_KEEP_ALIVE,
+    }, // Import AppSettings and Config
+    maintenance::MaintenanceReport,
+    model_updates::ModelUpdateState,
+    ollama::{OllamaModel, OllamaShowResponse},
+    remote_api::RemoteCommand,
     state::{
-        AppStatus, AppView, ColumnState, ModelColumn, SortDirection, SortState, UpdateMessage,
+        AppStatus, AppView, ColumnState, DiagnosticStep, ModelColumn, DIAGNOSTIC_STEPS, SortDirection, SortState, UpdateMessage,
+        WorkbenchState,
     },
     ui::{views, windows, widgets},
-    utils::{load_image_from_bytes, LOGO_BYTES},
+    utils::{format_digest, format_model_size, format_size, is_insecure_remote_host, parse_log_line, redact_url_credentials, unique_digest_chars, validate_ollama_host, with_default_tag, load_image_from_bytes, LOGO_BYTES},
 };
 
+// --- Keyboard Shortcuts ---
+const TOGGLE_LOGS_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Backtick);
+const QUICK_OPEN_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::P);
+const SETTINGS_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Comma);
+const ABOUT_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::NONE, egui::Key::F1);
+
+// --- Log Panel Layout ---
+// Height of the expanded log panel: at least this much, so it never opens as a sliver...
+const LOG_PANEL_MIN_HEIGHT: f32 = 120.0;
+// ...and at most this share of the space below the top bar, so the main view keeps room.
+const LOG_PANEL_MAX_SHARE: f32 = 0.6;
+
 // --- Main Application Struct ---
 
 /// Holds the state and logic for the LlamaLift application.
@@ -50,39 +85,115 @@ pub struct OllamaPullerApp {
     logs: Arc<Mutex<Vec<String>>>,
     logs_string_cache: String,
     logs_dirty: bool,
+    recent_log_messages: VecDeque<(bool, String)>, // (from logger, message) of the last lines, for deduplication
     logs_collapsed: bool,
+    log_panel_height: f32, // Height of the log panel last frame
+    main_view_spare_height: f32, // Height the main view left unused last frame (e.g. a short model list)
+    scroll_logs_to_newest: bool, // Set when the log panel is expanded because of an error
+    status_was_error: bool, // Whether the status was an error last frame, to notice new errors
+    error_toast_dismissed: bool, // The Manage view's error notice was closed (until the next error)
     show_settings_window: bool,
     show_about_window: bool,
+    show_effective_config: bool,
+    show_diagnostics: bool,
+    diagnostics_run: u64, // Id of the latest diagnostics run; steps of older runs are ignored
+    diagnostics_host: String, // Host the latest diagnostics run checks
+    diagnostic_steps: Vec<DiagnosticStep>,
+    effective_config_text: String, // Rendered when the Effective Configuration window opens or refreshes
     show_select_columns_window: bool,
+    show_compare_window: bool,
+    info_model: Option<String>, // Model shown in the Model Info window, if open
+    info_full_digest: bool, // Show the full digest in the Model Info window instead of the short form
+    show_quick_open: bool,
+    quick_open_query: String,
+    quick_open_selected: usize, // Index into the ranked quick-open matches
+    show_maintenance_window: bool,
+    maintenance_report: Option<MaintenanceReport>, // Last model store scan
+    maintenance_confirm_cleanup: bool, // Cleanup clicked, waiting for the second confirmation
+    workbench: WorkbenchState, // Prompt Workbench inputs/output (session only)
+    show_reload_config_confirm: bool,
     current_view: AppView,
     model_to_delete: Option<String>,
+    model_to_load: Option<String>, // Set by the Manage view's load button, started after drawing
     copy_logs_requested: bool,
+    http_warning_dismissed: bool, // Per session only
+    host_quick_edit: String, // Top panel host field, applied on Enter
+    version_warning_dismissed: bool, // Per server version check
+    failed_models: Vec<String>,
+    batch_models: Vec<String>, // Models of the current/last batch, in pull order
+    cancelled_models: Vec<String>, // Models of the current/last batch whose pull was cancelled
+    batch_cancel_flags: HashMap<String, Arc<AtomicBool>>, // Per-model cancel flags of the current batch
+    pull_digest_notes: Vec<String>, // "updated" / "already up to date" per model of the last batch
+    pending_pull_confirmation: Option<Vec<String>>, // Batch awaiting the download confirmation
+    batch_completed_at: Option<Instant>,
+    last_refresh_click: Option<Instant>,
+    selected_models: HashSet<String>,
+    model_picker_filter: String,
+    highlighted_model: Option<String>, // Chosen in the "Go to model" picker
+    scroll_to_model: Option<String>, // One-shot scroll request for the models table
+    frozen_table_scroll_offset: f32, // Shared vertical offset of the split table (frozen Name column)
 
     // --- Application State & Data ---
     progress: Arc<Mutex<f32>>,
     status_text: Arc<Mutex<String>>,
     status: Arc<Mutex<AppStatus>>,
     listed_models: Arc<Mutex<Vec<OllamaModel>>>,
-
+    last_model_list_at: Option<DateTime<Utc>>, // When the model list was last received
+    running_models: HashSet<String>,
+    host_generation: u64,    // Bumped whenever the Ollama host changes; tags results of host requests
+    generation_host: String, // The host the current generation belongs to
+    model_last_used: HashMap<String, DateTime<Utc>>,
+    last_update_check: Option<DateTime<Utc>>, // Kept here (like model_last_used) so a settings save doesn't clobber it
+    latest_release: Option<(String, String)>, // (version, release page URL)
+    update_check_attempted: bool, // Per session, so failures aren't retried every frame
+    model_update_states: HashMap<String, (String, ModelUpdateState)>, // By model name, with the local digest checked
+    model_update_check_running: bool,
+    last_model_update_check: Option<DateTime<Utc>>, // Per session; the results aren't saved either
+    remote_api_task: Option<(u16, tokio::task::JoinHandle<()>)>, // Running remote API server and its port
+    last_ps_poll: Option<Instant>,
+    last_interaction: Instant, // Last focused input, for pausing background polling when idle
+    background_polling_paused: bool,
+    model_details: HashMap<String, Result<OllamaShowResponse, String>>,
+    model_details_pending: HashSet<String>,
+    server_version: Option<String>,
+    version_checked_host: Option<String>, // Host the version was last requested from
     // --- Configuration & Resources ---
     settings: AppSettings,
+    last_saved_settings: AppSettings, // What is currently on disk, to skip no-op writes
     config_path: Option<PathBuf>,
+    config_in_memory_only: bool, // Set by READ_ONLY_CONFIG or after the first failed write
+    model_store_space: Option<(PathBuf, u64)>, // Local model store and its free space, if found
+    reclaimable_sizes: Option<HashMap<String, u64>>, // Unique footprint per model, computed on request
+    reclaimable_sizes_running: bool, // Whether the reclaimable sizes are being computed
+    applied_always_on_top: Option<bool>, // Window level last sent to the viewport
     logo_texture: Option<egui::TextureHandle>,
+    logo_reload_attempted: bool, // Once per About window opening, reset when it closes
 
     // --- Table State & Cache ---
     model_column_states: Vec<ColumnState>,
     model_sort_state: SortState,
+    model_group_by_base: bool,
+    show_only_loaded: bool, // Manage view lists only the models currently loaded (from /api/ps)
+    model_group_open: HashMap<String, bool>, // Open state of each tag group, keyed by base name
     manage_view_cache: Vec<OllamaModel>,
     manage_view_cache_dirty: bool,
 
     // --- Temporary State for Windows ---
     pending_column_states: Option<Vec<ColumnState>>,
     pending_settings: Option<AppSettings>,
+    detected_models_dir: Option<PathBuf>, // Model store found without the override, refreshed when Settings or Maintenance opens
+    maintenance_models_dir: Option<PathBuf>, // Model store the Maintenance window works on, resolved when it opens
+    new_alias_name: String,   // Settings → Model Aliases input fields
+    new_alias_target: String,
+    new_header_name: String, // Settings → Custom Headers input fields
+    new_header_value: String,
 
     // --- Communication & Async ---
     task_update_sender: Sender<UpdateMessage>, // Sender clone passed from main.rs
     update_receiver: Receiver<UpdateMessage>, // Receiver passed from main.rs
-    rt: Arc<Runtime>,
+    rt: Option<Arc<Runtime>>, // None if the runtime failed to start (network features disabled)
+    runtime_error: Option<String>,
+    show_runtime_error_window: bool,
 }
 
 // --- Application Implementation ---
@@ -96,7 +207,11 @@ impl OllamaPullerApp {
     ) -> Self {
         info!("Running OllamaPullerApp::new - v{}", SCRIPT_VERSION);
         // --- Load Settings ---
-        let (mut settings, config_path) = match confy::load::<AppSettings>(APP_NAME, None) {
+        let mut config_in_memory_only = config::read_only_config_requested();
+        if config_in_memory_only {
+            info!("{} is set, settings will not be written to disk.", config::READ_ONLY_CONFIG_ENV);
+        }
+        let (mut settings, config_path) = match config::load_settings(config_in_memory_only) {
             Ok(cfg) => {
                 info!("Successfully loaded settings from config file.");
                 // --- ADDED DEBUG LOG ---
@@ -114,52 +229,27 @@ impl OllamaPullerApp {
                 );
                 let default_settings = AppSettings::default();
                 let config_path = confy::get_configuration_file_path(APP_NAME, None).ok();
-                // Attempt to store defaults, log error if it fails
-                if let Err(store_err) = confy::store(APP_NAME, None, &default_settings) {
-                    error!("Failed to store default settings: {}", store_err);
+                // Attempt to store defaults; if that fails (e.g. read-only filesystem),
+                // keep running with in-memory settings instead of failing on every save
+                if config_in_memory_only {
+                    debug!("Skipping storing default settings (read-only config).");
+                } else if let Err(store_err) = confy::store(APP_NAME, None, &default_settings) {
+                    warn!(
+                        "Failed to store default settings ({}). Continuing with in-memory settings only.",
+                        store_err
+                    );
+                    config_in_memory_only = true;
                 } else {
                     info!("Stored default settings.");
                 }
                 (default_settings, config_path)
             }
         };
+        let last_saved_settings = settings.clone();
 
         // --- Ensure Column States Match Available Columns ---
-        // This handles cases where new columns are added to the app
-        // but are not yet in the saved config.
-        let all_cols_enum: HashSet<ModelColumn> =
-            ModelColumn::all().into_iter().collect();
-        let current_cols_enum: HashSet<ModelColumn> =
-            settings
-                .model_column_states
-                .iter()
-                .map(|cs| cs.column.clone())
-                .collect();
-
-        let mut needs_resave = false;
-        if all_cols_enum != current_cols_enum {
-            warn!("Mismatch between available columns and saved column states. Updating configuration.");
-            let mut new_states = Vec::new();
-            // Use the public default function from the config module
-            let default_states = config::default_column_states();
-
-            // Keep existing states and add new ones with default visibility/width
-            // Ensure the order from default_states is maintained
-            for default_state in default_states {
-                if let Some(existing_state) = settings
-                    .model_column_states
-                    .iter()
-                    .find(|cs| cs.column == default_state.column)
-                {
-                    new_states.push(existing_state.clone());
-                } else {
-                    info!("Adding new column state for: {:?}", default_state.column);
-                    new_states.push(default_state);
-                }
-            }
-            settings.model_column_states = new_states;
-            needs_resave = true;
-        }
+        let needs_resave = config::reconcile_column_states(&mut settings);
+        config::set_gui_log_level(&settings.gui_log_level);
         // --- End Column State Check ---
 
         // Log the effective configuration path and settings being used
@@ -175,14 +265,25 @@ impl OllamaPullerApp {
         debug!("Column States: {:?}", settings.model_column_states);
         debug!("Sort State (loaded into settings struct): {:?}", settings.model_sort_state);
         info!("--------------------------------");
+        if let Some(warn_msg) = config::invalid_models_dir_override(&settings) {
+            warn!("{}", warn_msg);
+            let _ = task_update_sender.send(UpdateMessage::Log(format!("WARN: {}", warn_msg)));
+        }
 
-        // Create the Tokio runtime
-        let rt = Arc::new(
-            tokio::runtime::Builder::new_multi_thread()
-                .enable_all()
-                .build()
-                .expect("Failed to create Tokio runtime"),
-        );
+        // Create the Tokio runtime. If that fails (e.g. thread limits on locked-down systems),
+        // keep the app open without network features and tell the user instead of panicking.
+        let (rt, runtime_error) = match tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+        {
+            Ok(runtime) => (Some(Arc::new(runtime)), None),
+            Err(e) => {
+                let error_msg = format!("Failed to create Tokio runtime: {}", e);
+                error!("{}", error_msg);
+                let _ = task_update_sender.send(UpdateMessage::Log(format!("ERROR: {}", error_msg)));
+                (None, Some(error_msg))
+            }
+        };
 
         // Load the logo image for the About window
         let logo_texture = load_image_from_bytes(&cc.egui_ctx, "logo", LOGO_BYTES);
@@ -212,30 +313,107 @@ impl OllamaPullerApp {
             logs: Arc::new(Mutex::new(Vec::new())),
             logs_string_cache: String::new(),
             logs_dirty: true,
+            recent_log_messages: VecDeque::new(),
             logs_collapsed: true,
+            log_panel_height: 0.0,
+            main_view_spare_height: 0.0,
+            scroll_logs_to_newest: false,
+            status_was_error: false,
+            error_toast_dismissed: false,
             progress: Arc::new(Mutex::new(0.0)),
             status_text: Arc::new(Mutex::new("Idle".to_string())),
             status: Arc::new(Mutex::new(AppStatus::Idle)),
             show_settings_window: false,
             show_about_window: false,
+            show_effective_config: false,
+            show_diagnostics: false,
+            diagnostics_run: 0,
+            diagnostics_host: String::new(),
+            diagnostic_steps: Vec::new(),
+            effective_config_text: String::new(),
             show_select_columns_window: false,
+            show_compare_window: false,
+            info_model: None,
+            info_full_digest: false,
+            show_quick_open: false,
+            quick_open_query: String::new(),
+            quick_open_selected: 0,
+            show_maintenance_window: false,
+            maintenance_report: None,
+            maintenance_confirm_cleanup: false,
+            workbench: WorkbenchState::default(),
+            show_reload_config_confirm: false,
             current_view: AppView::ManageModels, // Start on Manage view
             listed_models: Arc::new(Mutex::new(Vec::new())),
+            last_model_list_at: None,
+            running_models: HashSet::new(),
+            host_generation: 0,
+            generation_host: settings.ollama_host.clone(),
+            model_last_used: settings.model_last_used.clone(),
+            last_update_check: settings.last_update_check,
+            latest_release: settings.latest_release.clone(),
+            update_check_attempted: false,
+            model_update_states: HashMap::new(),
+            model_update_check_running: false,
+            last_model_update_check: None,
+            remote_api_task: None,
+            last_ps_poll: None,
+            last_interaction: Instant::now(),
+            background_polling_paused: false,
+            model_details: HashMap::new(),
+            model_details_pending: HashSet::new(),
+            server_version: None,
+            version_checked_host: None,
             model_to_delete: None,
+            model_to_load: None,
             copy_logs_requested: false,
+            http_warning_dismissed: false,
+            host_quick_edit: String::new(), // Synced from settings while not being edited
+            version_warning_dismissed: false,
+            failed_models: Vec::new(),
+            batch_models: Vec::new(),
+            cancelled_models: Vec::new(),
+            batch_cancel_flags: HashMap::new(),
+            pull_digest_notes: Vec::new(),
+            pending_pull_confirmation: None,
+            batch_completed_at: None,
+            last_refresh_click: None,
+            selected_models: HashSet::new(),
+            model_picker_filter: String::new(),
+            highlighted_model: None,
+            scroll_to_model: None,
+            frozen_table_scroll_offset: 0.0,
             // Load table state from settings
             model_column_states: settings.model_column_states.clone(),
             model_sort_state: settings.model_sort_state.clone(),
+            model_group_by_base: settings.model_group_by_base,
+            show_only_loaded: settings.remember_filters && settings.show_only_loaded,
+            model_group_open: HashMap::new(),
             manage_view_cache: Vec::new(), // Initialize cache
             manage_view_cache_dirty: true,  // Mark cache dirty initially
             pending_column_states: None, // Initialize new field
             pending_settings: None, // Initialize pending settings state (NEW)
+            detected_models_dir: config::detect_models_dir(),
+            maintenance_models_dir: None,
+            new_alias_name: String::new(),
+            new_alias_target: String::new(),
+            new_header_name: String::new(),
+            new_header_value: String::new(),
             settings, // Move settings into the struct
+            last_saved_settings,
             task_update_sender,
             update_receiver,
             rt,
+            show_runtime_error_window: runtime_error.is_some(),
+            runtime_error,
             config_path,
+            config_in_memory_only,
+            applied_always_on_top: None, // Applied on the first frame
+            model_store_space: None, // Measured with each model list update
+            reclaimable_sizes: None,
+            reclaimable_sizes_running: false,
             logo_texture,
+            logo_reload_attempted: false,
         };
         debug!("Initialized app state with Sort State: {:?}", app.model_sort_state);
 
@@ -256,11 +434,39 @@ impl OllamaPullerApp {
     fn rebuild_log_cache(&mut self) {
         if self.logs_dirty {
             let logs_vec = self.logs.lock().unwrap();
-            self.logs_string_cache = logs_vec.join("\n");
+            self.logs_string_cache = if self.settings.logs_newest_first {
+                logs_vec.iter().rev().cloned().collect::<Vec<_>>().join("\n")
+            } else {
+                logs_vec.join("\n")
+            };
             self.logs_dirty = false;
         }
     }
 
+    /// Adds a line to the log panel, unless it is more verbose than the log panel level or repeats a
+    /// recent line: many messages are both logged (reaching the panel through the logger) and sent to
+    /// the panel directly, so the same message from the other path is shown only once.
+    fn push_log_line(&mut self, log_line: String) {
+        const DEDUP_WINDOW: usize = 32; // Recent lines compared against
+        let (level, from_logger, message) = parse_log_line(&log_line);
+        if level.is_some_and(|level| level > config::gui_log_level()) {
+            return;
+        }
+        let is_duplicate = self
+            .recent_log_messages
+            .iter()
+            .any(|(other_from_logger, other)| *other_from_logger != from_logger && other == message);
+        if is_duplicate {
+            return;
+        }
+        if self.recent_log_messages.len() >= DEDUP_WINDOW {
+            self.recent_log_messages.pop_front();
+        }
+        self.recent_log_messages.push_back((from_logger, message.to_string()));
+        self.logs.lock().unwrap().push(log_line);
+        self.logs_dirty = true;
+    }
+
     /// Rebuilds the cached and sorted model list for the Manage view if dirty.
     fn rebuild_manage_view_cache(&mut self) {
         // This function is called when the cache is marked dirty
@@ -270,6 +476,50 @@ impl OllamaPullerApp {
         );
 
         let mut models = self.listed_models.lock().unwrap().clone();
+
+        // Shortened digests get longer where needed to keep different models apart
+        let digest_chars = if self.settings.digest_disambiguate {
+            let digests: Vec<&str> = models.iter().map(|m| m.digest.as_str()).collect();
+            unique_digest_chars(&digests, self.settings.digest_display_chars, self.settings.digest_strip_prefix)
+        } else {
+            self.settings.digest_display_chars
+        };
+        if digest_chars != self.settings.digest_display_chars {
+            debug!("Showing {} digest characters to keep digests distinguishable.", digest_chars);
+        }
+
+        // Filter after working out the digest length, so it doesn't change when the filter is toggled
+        if self.show_only_loaded {
+            models.retain(|m| self.running_models.contains(&m.name));
+        }
+
+        // Attach the app-maintained last-used timestamps, formatted in the configured timezone
+        let local_tz = Tz::from_str(&self.settings.tz).unwrap_or(Tz::UTC);
+        for model in models.iter_mut() {
+            model.last_used_dt = self.model_last_used.get(&model.name).copied();
+            model.last_used_local = model
+                .last_used_dt
+                .map(|dt| dt.with_timezone(&local_tz).format("%Y-%m-%d %H:%M:%S").to_string());
+            model.digest_display = format_digest(
+                &model.digest,
+                digest_chars,
+                self.settings.digest_strip_prefix,
+            );
+            // Only while the model still has the digest that was checked (pulling the update changes it)
+            model.update_available = self.model_update_states.get(&model.name).is_some_and(|(digest, state)| {
+                *state == ModelUpdateState::UpdateAvailable && *digest == model.digest
+            });
+            (model.lineage, model.context_length) = match self.model_details.get(&model.name) {
+                Some(Ok(details)) => (Some(details.lineage()), details.context_length()),
+                _ => (None, None),
+            };
+            model.reclaimable_size = self
+                .reclaimable_sizes
+                .as_ref()
+                .and_then(|sizes| sizes.get(&model.name).copied());
+            model.size_human = format_model_size(model.size, self.settings.dash_for_unknown_size);
+        }
+
         let sort_col = &self.model_sort_state.column;
         let sort_dir = &self.model_sort_state.direction;
 
@@ -278,13 +528,19 @@ impl OllamaPullerApp {
             let ordering = match sort_col {
                 ModelColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
                 ModelColumn::Size => a.size.cmp(&b.size),
-                ModelColumn::Modified => a.modified_dt.cmp(&b.modified_dt), // Compare Option<DateTime>
+                // Applies the direction itself, keeping unparseable dates last
+                ModelColumn::Modified => return compare_modified(a, b, sort_dir),
                 ModelColumn::Digest => a.digest.cmp(&b.digest),
                 ModelColumn::Format => a.details.format.cmp(&b.details.format), // Compare Option<String>
                 ModelColumn::Family => a.details.family.cmp(&b.details.family),
-                ModelColumn::Families => a.details.families.cmp(&b.details.families), // Compare Option<Vec<String>>
+                // Models without families (missing, null or empty) sort first
+                ModelColumn::Families => a.details.families_list().cmp(b.details.families_list()),
                 ModelColumn::ParameterSize => a.details.parameter_size.cmp(&b.details.parameter_size),
                 ModelColumn::QuantizationLevel => a.details.quantization_level.cmp(&b.details.quantization_level),
+                ModelColumn::LastUsed => a.last_used_dt.cmp(&b.last_used_dt), // "never" (None) sorts first
+                ModelColumn::Lineage => a.lineage.cmp(&b.lineage), // Unknown (None) sorts first
+                ModelColumn::ContextLength => a.context_length.cmp(&b.context_length), // Numeric, unknown first
+                ModelColumn::Reclaimable => a.reclaimable_size.cmp(&b.reclaimable_size), // Not computed first
             };
 
             // Apply direction
@@ -306,6 +562,7 @@ impl OllamaPullerApp {
     fn get_current_config(&self) -> Config {
         Config {
             ollama_host: self.settings.ollama_host.clone(),
+            host_generation: self.host_generation,
             tz: Tz::from_str(&self.settings.tz).unwrap_or_else(|_| {
                 warn!(
                     "Invalid TZ '{}' in settings during runtime config fetch, falling back to UTC.",
@@ -318,6 +575,79 @@ impl OllamaPullerApp {
                 )));
                 Tz::UTC
             }),
+            stream_pulls: self.settings.stream_pulls,
+            max_redirects: self.settings.max_redirects,
+            follow_pull_redirects: self.settings.follow_pull_redirects,
+            // Clamped here too, as the config file may have been edited by hand
+            max_retries: self.settings.max_retries.min(MAX_RETRIES_LIMIT),
+            base_backoff_ms: self
+                .settings
+                .base_backoff_ms
+                .clamp(*BACKOFF_MS_RANGE.start(), *BACKOFF_MS_RANGE.end()),
+            custom_headers: self.settings.custom_headers.clone(),
+            cli_list_fallback: self.settings.cli_list_fallback,
+            disk_guard: config::disk_guard(&self.settings, self.detected_models_dir.as_deref()),
+        }
+    }
+
+    /// Copies the table/view state kept on the app struct into `self.settings`.
+    fn sync_settings_from_state(&mut self) {
+        self.settings.model_column_states = self.model_column_states.clone();
+        self.settings.model_sort_state = self.model_sort_state.clone(); // Ensure latest sort state is copied
+        self.settings.model_group_by_base = self.model_group_by_base;
+        self.settings.show_only_loaded = self.settings.remember_filters && self.show_only_loaded;
+        self.settings.model_last_used = self.model_last_used.clone();
+        self.settings.last_update_check = self.last_update_check;
+        self.settings.latest_release = self.latest_release.clone();
+    }
+
+    /// Returns true if the in-app settings differ from what was last written to disk.
+    fn has_unsaved_settings(&mut self) -> bool {
+        self.sync_settings_from_state();
+        self.settings != self.last_saved_settings
+    }
+
+    /// Re-reads the config file from disk and applies it, discarding in-app changes.
+    /// Load errors are surfaced in the status text and logs; the current settings are kept then.
+    fn reload_settings_from_disk(&mut self) {
+        info!("Reloading settings from config file...");
+        match confy::load::<AppSettings>(APP_NAME, None) {
+            Ok(mut reloaded) => {
+                let needs_resave = config::reconcile_column_states(&mut reloaded);
+                self.last_saved_settings = reloaded.clone();
+                self.model_column_states = reloaded.model_column_states.clone();
+                self.model_sort_state = reloaded.model_sort_state.clone();
+                self.model_group_by_base = reloaded.model_group_by_base;
+                if reloaded.remember_filters {
+                    self.show_only_loaded = reloaded.show_only_loaded;
+                }
+                self.model_last_used = reloaded.model_last_used.clone();
+                self.last_update_check = reloaded.last_update_check;
+                self.latest_release = reloaded.latest_release.clone();
+                config::set_gui_log_level(&reloaded.gui_log_level);
+                self.settings = reloaded;
+                // Drop any half-edited settings window state, it was based on the old values
+                self.pending_settings = None;
+                self.show_settings_window = false;
+                self.manage_view_cache_dirty = true;
+                self.logs_dirty = true; // The log order may have changed
+                if needs_resave {
+                    self.save_settings();
+                }
+                info!("Settings reloaded from config file.");
+                let _ = self.task_update_sender.send(UpdateMessage::Log(
+                    "INFO: Settings reloaded from config file.".to_string(),
+                ));
+                *self.status_text.lock().unwrap() = "Settings reloaded from config file.".to_string();
+            }
+            Err(e) => {
+                error!("Failed to reload config file: {}", e);
+                let _ = self.task_update_sender.send(UpdateMessage::Log(format!(
+                    "ERROR: Failed to reload config file: {}",
+                    e
+                )));
+                *self.status_text.lock().unwrap() = format!("Failed to reload config: {}", e);
+            }
         }
     }
 
@@ -325,26 +655,38 @@ impl OllamaPullerApp {
     fn save_settings(&mut self) {
         // Changed to &mut self
         // Update the settings struct with the current app state before saving
-        self.settings.model_column_states = self.model_column_states.clone();
-        self.settings.model_sort_state = self.model_sort_state.clone(); // Ensure latest sort state is copied
+        self.sync_settings_from_state();
 
         // --- ADDED DEBUG LOG ---
         debug!("Attempting to save settings. Sort State to be saved: {:?}", self.settings.model_sort_state);
         // --- END DEBUG LOG ---
 
+        if self.config_in_memory_only {
+            debug!("Config is in-memory only, not writing settings to disk.");
+            return;
+        }
+        if self.settings == self.last_saved_settings {
+            debug!("Settings unchanged since last save, skipping write.");
+            return;
+        }
+
         match confy::store(APP_NAME, None, &self.settings) {
             Ok(_) => {
+                self.last_saved_settings = self.settings.clone();
                 info!("Settings saved successfully.");
                 let _ = self
                     .task_update_sender
                     .send(UpdateMessage::Log("INFO: Settings saved.".to_string()));
             }
             Err(e) => {
-                error!("Failed to save settings: {}", e);
+                // Warn once and stop trying, rather than logging an error on every save
+                warn!("Failed to save settings: {}. Keeping settings in memory only.", e);
                 let _ = self.task_update_sender.send(UpdateMessage::Log(format!(
-                    "ERROR: Failed to save settings: {}",
+                    "WARN: Failed to save settings: {}. Keeping settings in memory only.",
                     e
                 )));
+                self.config_in_memory_only = true;
+                return; // Nothing was saved
             }
         }
         info!("--- Updated Configuration Saved ---");
@@ -356,12 +698,35 @@ impl OllamaPullerApp {
         info!("--------------------------------");
     }
 
+    /// Spawns a future on the Tokio runtime. If the runtime failed to start, logs the problem,
+    /// sets the status to an error and returns false.
+    fn spawn_task<F>(&self, future: F) -> bool
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        match &self.rt {
+            Some(rt) => {
+                rt.spawn(future);
+                true
+            }
+            None => {
+                let error_msg = "Network features are unavailable: the async runtime failed to start.";
+                error!("{}", error_msg);
+                let _ = self
+                    .task_update_sender
+                    .send(UpdateMessage::Log(format!("ERROR: {}", error_msg)));
+                *self.status_text.lock().unwrap() = error_msg.to_string();
+                *self.status.lock().unwrap() = AppStatus::Error(error_msg.to_string());
+                false
+            }
+        }
+    }
+
     /// Spawns an asynchronous task to refresh the list of models from the Ollama server.
     fn refresh_model_list(&self) {
         // Keep as &self, state changes happen via messages
         let config = self.get_current_config();
         let sender = self.task_update_sender.clone();
-        let rt_handle = self.rt.clone();
         let status_arc = self.status.clone();
 
         // Use try_lock to avoid blocking UI if lock is held (though unlikely here)
@@ -395,11 +760,11 @@ impl OllamaPullerApp {
         let _ = sender.send(UpdateMessage::StatusText("Listing models...".to_string()));
         info!("Refreshing model list...");
 
-        rt_handle.spawn(async move {
+        self.spawn_task(async move {
             match ollama::list_models_async(&config, sender.clone()).await {
                 Ok(models) => {
                     info!("Successfully listed {} models.", models.len());
-                    let _ = sender.send(UpdateMessage::ModelList(models)); // Send the new list
+                    let _ = sender.send(UpdateMessage::ModelList(config.host_generation, models)); // Send the new list
                     let _ = sender.send(UpdateMessage::StatusText(
                         "Model list updated.".to_string(),
                     ));
@@ -415,12 +780,494 @@ impl OllamaPullerApp {
         });
     }
 
+    /// Refreshes the model list, the loaded models and the server version in one go ("Refresh All"):
+    /// the three requests run concurrently and the busy status lasts until all of them are done.
+    /// Only a failed model list is an error; the loaded models and the version are reported as warnings.
+    fn refresh_all(&mut self) {
+        {
+            let mut current_status = self.status.lock().unwrap();
+            if !matches!(
+                *current_status,
+                AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+            ) {
+                warn!("Cannot refresh while another operation ({:?}) is in progress.", *current_status);
+                let _ = self.task_update_sender.send(UpdateMessage::Log(format!(
+                    "WARN: Cannot refresh during {:?}.",
+                    *current_status
+                )));
+                return;
+            }
+            *current_status = AppStatus::ListingModels;
+        }
+        let config = self.get_current_config();
+        let sender = self.task_update_sender.clone();
+        let host = self.settings.ollama_host.clone();
+        // The combined refresh covers the periodic /api/ps poll and the version check
+        self.version_checked_host = Some(host.clone());
+        self.last_ps_poll = Some(Instant::now());
+
+        let _ = sender.send(UpdateMessage::StatusText(
+            "Refreshing models, loaded models and server version...".to_string(),
+        ));
+        info!("Refreshing model list, loaded models and server version...");
+
+        self.spawn_task(async move {
+            let (models, running, version) = tokio::join!(
+                ollama::list_models_async(&config, sender.clone()),
+                ollama::list_running_models_async(&config),
+                ollama::get_server_version_async(&config),
+            );
+
+            let mut unavailable = Vec::new();
+            match running {
+                Ok(running) => {
+                    let names = running.into_iter().map(|m| m.name).collect();
+                    let _ = sender.send(UpdateMessage::RunningModels(config.host_generation, names));
+                }
+                Err(e) => {
+                    warn!("Failed to fetch the loaded models: {}", e);
+                    let _ = sender.send(UpdateMessage::Log(format!("WARN: Failed to fetch the loaded models: {}", e)));
+                    unavailable.push("loaded models");
+                }
+            }
+            match version {
+                Ok(version) => {
+                    let _ = sender.send(UpdateMessage::ServerVersion(host, version));
+                }
+                Err(e) => {
+                    warn!("Failed to fetch Ollama server version: {}", e);
+                    let _ = sender.send(UpdateMessage::Log(format!("WARN: Failed to fetch Ollama server version: {}", e)));
+                    unavailable.push("server version");
+                }
+            }
+            match models {
+                Ok(models) => {
+                    info!("Successfully listed {} models.", models.len());
+                    let _ = sender.send(UpdateMessage::ModelList(config.host_generation, models));
+                    let status_text = if unavailable.is_empty() {
+                        "Models, loaded models and server version updated.".to_string()
+                    } else {
+                        format!("Model list updated ({} unavailable, see logs).", unavailable.join(" and "))
+                    };
+                    let _ = sender.send(UpdateMessage::StatusText(status_text));
+                    let _ = sender.send(UpdateMessage::Status(AppStatus::Idle));
+                }
+                Err(e) => {
+                    error!("Failed to list models: {}", e);
+                    let _ = sender.send(UpdateMessage::StatusText(format!("Error listing models: {}", e)));
+                    let _ = sender.send(UpdateMessage::Status(AppStatus::Error(e.to_string())));
+                }
+            }
+        });
+    }
+
+    /// Spawns an asynchronous task to poll `/api/ps` for the currently loaded models.
+    /// Does not touch `AppStatus`, so it can run alongside other operations.
+    fn poll_running_models(&mut self) {
+        self.last_ps_poll = Some(Instant::now());
+        if self.rt.is_none() {
+            return; // Background polling silently stays off without a runtime
+        }
+        let config = self.get_current_config();
+        let sender = self.task_update_sender.clone();
+
+        self.spawn_task(async move {
+            match ollama::list_running_models_async(&config).await {
+                Ok(models) => {
+                    let names = models.into_iter().map(|m| m.name).collect();
+                    let _ = sender.send(UpdateMessage::RunningModels(config.host_generation, names));
+                }
+                Err(e) => {
+                    // Expected while the server is down; don't flood the UI log
+                    debug!("Failed to poll running models: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Switches to another Ollama host from the top panel field: validates it, saves it and
+    /// refreshes the model list (the server version check follows the host change automatically).
+    /// Refused while an operation is running, as it would talk to the old host.
+    fn apply_quick_host(&mut self) {
+        let new_host = self.host_quick_edit.trim().to_string();
+        if new_host == self.settings.ollama_host {
+            return;
+        }
+        let is_busy = !matches!(
+            *self.status.lock().unwrap(),
+            AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+        );
+        if is_busy {
+            warn!("Cannot switch host while an operation is in progress.");
+            let _ = self.task_update_sender.send(UpdateMessage::Log(
+                "WARN: Cannot switch host while an operation is in progress.".to_string(),
+            ));
+            return;
+        }
+        if let Err(e) = validate_ollama_host(&new_host) {
+            warn!("Invalid Ollama host: {}", e);
+            let _ = self
+                .task_update_sender
+                .send(UpdateMessage::Log(format!("WARN: Invalid Ollama host: {}", e)));
+            *self.status_text.lock().unwrap() = format!("Invalid Ollama host: {}", e);
+            return;
+        }
+        info!("Switching Ollama host from '{}' to '{}'.", self.settings.ollama_host, new_host);
+        self.settings.ollama_host = new_host;
+        self.save_settings();
+        self.sync_host_generation(); // Before refreshing, so the new list isn't taken for a stale one
+        self.refresh_model_list();
+    }
+
+    /// Forces the status back to Idle after the user reports it stuck (Help → Reset Stuck State), e.g. when
+    /// a dropped message left it at ListingModels with no task running. Also asks a running workbench
+    /// generation to stop; other tasks can't be stopped and may still post their results.
+    fn reset_stuck_state(&mut self, stuck_status: AppStatus) {
+        let warn_msg = format!("Status manually reset from {:?} to Idle.", stuck_status);
+        warn!("{}", warn_msg);
+        let _ = self.task_update_sender.send(UpdateMessage::Log(format!("WARN: {}", warn_msg)));
+        self.workbench.cancel_generation.store(true, std::sync::atomic::Ordering::Relaxed);
+        *self.status.lock().unwrap() = AppStatus::Idle;
+        *self.progress.lock().unwrap() = 0.0;
+        *self.status_text.lock().unwrap() = "Status reset.".to_string();
+    }
+
+    /// Starts a new host generation if the Ollama host changed (quick edit, Settings or a config reload).
+    /// Requests still running against the old host can't be recalled, but their results carry the old
+    /// generation and are ignored when they arrive, so they can't overwrite the new host's data.
+    /// Returns true if the host changed.
+    fn sync_host_generation(&mut self) -> bool {
+        if self.generation_host == self.settings.ollama_host {
+            return false;
+        }
+        self.host_generation += 1;
+        debug!(
+            "Ollama host changed from '{}' to '{}', starting host generation {}.",
+            self.generation_host, self.settings.ollama_host, self.host_generation
+        );
+        self.generation_host = self.settings.ollama_host.clone();
+        self.listed_models.lock().unwrap().clear(); // Don't show the old server's models
+        self.running_models.clear();
+        self.selected_models.clear();
+        self.highlighted_model = None;
+        self.model_details.clear();
+        self.model_details_pending.clear(); // Their answers will be ignored, allow fetching again
+        self.pull_digest_notes.clear();
+        self.model_update_states.clear();
+        self.model_update_check_running = false; // Its results will be ignored, allow checking again
+        self.last_model_update_check = None;
+        self.reclaimable_sizes = None;
+        self.reclaimable_sizes_running = false; // Its result will be ignored, allow computing again
+        self.manage_view_cache_dirty = true;
+        true
+    }
+
+    /// Merges a per-model refresh into `listed_models`: replaces (or adds) the `updated` entries and drops
+    /// the `removed` names, forgetting only their selections and cached details.
+    fn merge_model_changes(&mut self, updated: Vec<OllamaModel>, removed: Vec<String>) {
+        let affected: HashSet<String> = updated
+            .iter()
+            .map(|m| m.name.clone())
+            .chain(removed.iter().cloned())
+            .collect();
+        {
+            let mut listed_models = self.listed_models.lock().unwrap();
+            listed_models.retain(|m| !affected.contains(&m.name));
+            listed_models.extend(updated);
+        }
+        for name in &removed {
+            self.selected_models.remove(name);
+            if self.highlighted_model.as_ref() == Some(name) {
+                self.highlighted_model = None;
+            }
+        }
+        for name in &affected {
+            self.model_details.remove(name); // May be stale after a pull
+        }
+        self.reclaimable_sizes = None; // Pulls and deletes change which layers are shared
+        debug!("Merged per-model refresh for: {:?}", affected);
+        self.last_model_list_at = Some(Utc::now());
+        self.update_model_store_space();
+        self.manage_view_cache_dirty = true;
+    }
+
+    /// Computes how much deleting each model would free (its layers no other model shares) from the
+    /// local model store's manifests, off the UI thread. Run on request only, as it reads every manifest.
+    fn compute_reclaimable_sizes(&mut self) {
+        if self.reclaimable_sizes_running {
+            return;
+        }
+        let Some(models_dir) = config::resolve_models_dir(&self.settings, self.detected_models_dir.as_deref()) else {
+            let _ = self.task_update_sender.send(UpdateMessage::Log(
+                "WARN: No local model store found; reclaimable sizes need Ollama to run on this machine.".to_string(),
+            ));
+            return;
+        };
+        let generation = self.host_generation;
+        let sender = self.task_update_sender.clone();
+        self.reclaimable_sizes_running = self.spawn_task(async move {
+            let result = tokio::task::spawn_blocking(move || maintenance::unique_model_sizes(&models_dir))
+                .await
+                .unwrap_or_else(|e| Err(format!("the scan stopped unexpectedly: {}", e)));
+            let _ = sender.send(UpdateMessage::ReclaimableSizes(generation, result));
+        });
+    }
+
+    /// Re-measures the free space on the volume of the local model store (see `resolve_models_dir`).
+    fn update_model_store_space(&mut self) {
+        self.model_store_space = config::resolve_models_dir(&self.settings, self.detected_models_dir.as_deref()).and_then(|dir| {
+            match fs2::available_space(&dir) {
+                Ok(free) => Some((dir, free)),
+                Err(e) => {
+                    warn!("Could not determine free space for {}: {}", dir.display(), e);
+                    None
+                }
+            }
+        });
+    }
+
+    /// Spawns an asynchronous task to fetch the server version from `/api/version`.
+    /// Called on startup and whenever the Ollama host changes.
+    fn check_server_version(&mut self) {
+        let host = self.settings.ollama_host.clone();
+        self.version_checked_host = Some(host.clone());
+        self.server_version = None;
+        self.version_warning_dismissed = false;
+        if self.rt.is_none() {
+            return;
+        }
+        let config = self.get_current_config();
+        let sender = self.task_update_sender.clone();
+
+        self.spawn_task(async move {
+            match ollama::get_server_version_async(&config).await {
+                Ok(version) => {
+                    let _ = sender.send(UpdateMessage::ServerVersion(host, version));
+                }
+                Err(e) => {
+                    warn!("Failed to fetch Ollama server version: {}", e);
+                    let _ = sender.send(UpdateMessage::Log(format!(
+                        "WARN: Failed to fetch Ollama server version: {}",
+                        e
+                    )));
+                }
+            }
+        });
+    }
+
+    /// Checks GitHub for a newer LlamaLift release if enabled and the last check is over a day old.
+    /// Failures are only logged at debug level; the check is retried next session.
+    fn check_for_app_update(&mut self) {
+        if !self.settings.check_for_updates || self.update_check_attempted || self.rt.is_none() {
+            return;
+        }
+        let check_due = self.last_update_check.is_none_or(|last| {
+            Utc::now() - last >= chrono::Duration::hours(update_check::UPDATE_CHECK_INTERVAL_HOURS)
+        });
+        if !check_due {
+            return;
+        }
+        self.update_check_attempted = true;
+        let sender = self.task_update_sender.clone();
+
+        self.spawn_task(async move {
+            match update_check::fetch_latest_release_async().await {
+                Ok((version, url)) => {
+                    let _ = sender.send(UpdateMessage::LatestRelease(version, url));
+                }
+                Err(e) => debug!("Update check failed: {}", e),
+            }
+        });
+    }
+
+    /// Starts a connection diagnostics run against the configured host (see `ollama::run_diagnostics_async`).
+    /// Independent of the app status, so it also works while something else is running or stuck.
+    fn run_diagnostics(&mut self) {
+        self.diagnostics_run += 1;
+        self.diagnostics_host = self.settings.ollama_host.clone();
+        self.diagnostic_steps.clear();
+        info!("Running connection diagnostics against {}.", redact_url_credentials(&self.diagnostics_host));
+        let config = self.get_current_config();
+        let run_id = self.diagnostics_run;
+        let sender = self.task_update_sender.clone();
+        self.spawn_task(async move {
+            ollama::run_diagnostics_async(&config, run_id, sender).await;
+        });
+    }
+
+    /// Formats the latest diagnostics results as plain text, for the clipboard.
+    fn diagnostics_report(&self) -> String {
+        let mut lines = vec![format!(
+            "{} {} connection diagnostics for {}",
+            APP_NAME,
+            SCRIPT_VERSION,
+            redact_url_credentials(&self.diagnostics_host)
+        )];
+        for (index, name) in DIAGNOSTIC_STEPS.iter().enumerate() {
+            lines.push(match self.diagnostic_steps.iter().find(|step| step.index == index) {
+                Some(DiagnosticStep { result: Ok(detail), elapsed_ms, .. }) => format!("PASS {} ({} ms): {}", name, elapsed_ms, detail),
+                Some(DiagnosticStep { result: Err(detail), elapsed_ms, .. }) => format!("FAIL {} ({} ms): {}", name, elapsed_ms, detail),
+                None => format!("SKIP {}", name),
+            });
+        }
+        lines.join("\n")
+    }
+
+    /// Checks the listed models against registry.ollama.ai for newer versions of their tags: when
+    /// `manual` (the "Check Updates" button), or if enabled and the last check is over a day old.
+    fn check_model_updates(&mut self, manual: bool) {
+        if self.model_update_check_running || self.rt.is_none() {
+            return;
+        }
+        if !manual {
+            let check_due = self.settings.check_model_updates
+                && self.last_model_update_check.is_none_or(|last| {
+                    Utc::now() - last >= chrono::Duration::hours(model_updates::MODEL_UPDATE_CHECK_INTERVAL_HOURS)
+                });
+            if !check_due {
+                return;
+            }
+        }
+        let models: Vec<(String, String)> = self
+            .listed_models
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|m| (m.name.clone(), m.digest.clone()))
+            .collect();
+        if models.is_empty() {
+            return;
+        }
+        info!("Checking {} model(s) for updates on the registry.", models.len());
+        self.model_update_check_running = true;
+        self.last_model_update_check = Some(Utc::now()); // Also on failure, so it isn't retried every frame
+        let generation = self.host_generation;
+        let sender = self.task_update_sender.clone();
+
+        self.spawn_task(async move {
+            let results = model_updates::check_models_async(models).await;
+            let _ = sender.send(UpdateMessage::ModelUpdateStates(generation, results));
+        });
+    }
+
+    /// Starts, stops or restarts the local remote-control API to match the settings.
+    fn sync_remote_api(&mut self, ctx: &egui::Context) {
+        let wanted_port = self.settings.remote_api_enabled.then_some(self.settings.remote_api_port);
+        if self.remote_api_task.as_ref().map(|(port, _)| *port) == wanted_port {
+            return;
+        }
+        if let Some((port, task)) = self.remote_api_task.take() {
+            info!("Stopping the remote API on port {}.", port);
+            task.abort();
+        }
+        let (Some(port), Some(rt)) = (wanted_port, &self.rt) else {
+            return;
+        };
+        let state = remote_api::RemoteApiState {
+            status: self.status.clone(),
+            status_text: self.status_text.clone(),
+            progress: self.progress.clone(),
+            listed_models: self.listed_models.clone(),
+            sender: self.task_update_sender.clone(),
+            ctx: ctx.clone(),
+        };
+        self.remote_api_task = Some((port, rt.spawn(remote_api::serve(port, state))));
+    }
+
+    /// Runs a command received through the remote API, with the same busy guard as the GUI. Returns
+    /// the reason if it was refused, which the API answers with.
+    fn handle_remote_command(&mut self, command: RemoteCommand) -> Result<(), String> {
+        let is_busy = !matches!(
+            *self.status.lock().unwrap(),
+            AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+        );
+        if is_busy {
+            warn!("Refusing remote command {:?}: another operation is in progress.", command);
+            return Err("Another operation is in progress.".to_string());
+        }
+        match command {
+            RemoteCommand::Pull(models) => {
+                let models_to_pull: Vec<String> = models
+                    .iter()
+                    .map(|id| views::download_view::resolve_model_input(id, &self.settings))
+                    .collect();
+                self.current_view = AppView::Download; // Show the progress
+                views::download_view::start_batch_pull(self, models_to_pull);
+            }
+            RemoteCommand::Refresh => self.refresh_model_list(),
+        }
+        Ok(())
+    }
+
+    /// Returns the latest release (version, URL) if it is newer than the running version.
+    fn available_update(&self) -> Option<&(String, String)> {
+        self.latest_release
+            .as_ref()
+            .filter(|(version, _)| self.settings.check_for_updates && update_check::is_newer_than_current(version))
+    }
+
+    /// Spawns an asynchronous task to fetch `/api/show` details for a model,
+    /// unless they are already cached or being fetched.
+    fn fetch_model_details(&mut self, model_name: &str) {
+        if self.rt.is_none()
+            || self.model_details.contains_key(model_name)
+            || !self.model_details_pending.insert(model_name.to_string())
+        {
+            return;
+        }
+        let config = self.get_current_config();
+        let sender = self.task_update_sender.clone();
+        let model_name_clone = model_name.to_string();
+
+        self.spawn_task(async move {
+            let result = ollama::show_model_async(&model_name_clone, &config)
+                .await
+                .map_err(|e| e.to_string());
+            if let Err(e) = &result {
+                warn!("Failed to fetch details for model '{}': {}", model_name_clone, e);
+            }
+            let _ = sender.send(UpdateMessage::ModelDetails(config.host_generation, model_name_clone, result));
+        });
+    }
+
+    /// Prefetches `/api/show` details for the given (visible) models if enabled, so details are
+    /// ready before they are needed. Runs at most MAX_DETAIL_PREFETCHES requests at a time and
+    /// pauses while another operation is in progress.
+    fn prefetch_model_details(&mut self, model_names: &[String]) {
+        if !self.settings.prefetch_model_details {
+            return;
+        }
+        let is_busy = !matches!(
+            *self.status.lock().unwrap(),
+            AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+        );
+        if is_busy {
+            return;
+        }
+        for model_name in model_names {
+            if self.model_details_pending.len() >= MAX_DETAIL_PREFETCHES {
+                break;
+            }
+            if !self.model_details.contains_key(model_name) && !self.model_details_pending.contains(model_name) {
+                debug!("Prefetching details for '{}'.", model_name);
+                self.fetch_model_details(model_name);
+            }
+        }
+    }
+
+    /// Plays the completion chime if LlamaLift was built with the `sound` feature.
+    fn play_completion_sound(success: bool) {
+        #[cfg(feature = "sound")]
+        sound::play_completion_chime(success);
+        #[cfg(not(feature = "sound"))]
+        debug!("Built without the 'sound' feature, skipping chime (success: {}).", success);
+    }
+
     /// Spawns an asynchronous task to delete a specified model from the Ollama server.
     fn trigger_delete_model(&self, model_name: &str) {
         // Keep as &self
         let config = self.get_current_config();
         let sender = self.task_update_sender.clone();
-        let rt_handle = self.rt.clone();
         let status_arc = self.status.clone();
         let model_name_clone = model_name.to_string();
 
@@ -453,7 +1300,7 @@ impl OllamaPullerApp {
         )));
         info!("Attempting to delete model {}...", model_name_clone);
 
-        rt_handle.spawn(async move {
+        self.spawn_task(async move {
             match ollama::delete_model_async(&model_name_clone, &config, sender.clone()).await {
                 Ok(_) => {
                     info!("Successfully deleted model '{}'.", model_name_clone);
@@ -480,8 +1327,120 @@ impl OllamaPullerApp {
             }
         });
     }
+
+    /// Spawns an asynchronous task to load a model into memory (empty-prompt `/api/generate`).
+    fn trigger_warm_model(&self, model_name: &str) {
+        let config = self.get_current_config();
+        let sender = self.task_update_sender.clone();
+        let status_arc = self.status.clone();
+        let model_name_clone = model_name.to_string();
+
+        if let Ok(mut current_status) = status_arc.try_lock() {
+            if !matches!(
+                *current_status,
+                AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+            ) {
+                warn!(
+                    "Cannot load model while another operation ({:?}) is in progress.",
+                    *current_status
+                );
+                let _ = sender.send(UpdateMessage::Log(format!(
+                    "WARN: Cannot load model during {:?}.",
+                    *current_status
+                )));
+                return;
+            }
+            *current_status = AppStatus::LoadingModel(model_name_clone.clone());
+        } else {
+            warn!("Could not acquire status lock to start loading a model.");
+            return;
+        }
+
+        let _ = sender.send(UpdateMessage::StatusText(format!(
+            "Loading model {} into memory...",
+            model_name_clone
+        )));
+        info!("Loading model {} into memory...", model_name_clone);
+
+        self.spawn_task(async move {
+            match ollama::warm_model_async(&model_name_clone, WARM_MODEL_KEEP_ALIVE, &config).await {
+                Ok(_) => {
+                    info!("Model '{}' loaded into memory.", model_name_clone);
+                    let _ = sender.send(UpdateMessage::Log(format!(
+                        "INFO: Model '{}' loaded into memory (keep-alive {}).",
+                        model_name_clone, WARM_MODEL_KEEP_ALIVE
+                    )));
+                    let _ = sender.send(UpdateMessage::StatusText(format!(
+                        "Model '{}' loaded into memory.",
+                        model_name_clone
+                    )));
+                    let _ = sender.send(UpdateMessage::Status(AppStatus::Success));
+                }
+                Err(e) => {
+                    error!("Failed to load model '{}': {}", model_name_clone, e);
+                    let _ = sender.send(UpdateMessage::Log(format!(
+                        "ERROR: Failed to load model '{}': {}",
+                        model_name_clone, e
+                    )));
+                    let _ = sender.send(UpdateMessage::StatusText(format!(
+                        "Error loading model: {}",
+                        e
+                    )));
+                    let _ = sender.send(UpdateMessage::Status(AppStatus::Error(e.to_string())));
+                }
+            }
+        });
+    }
 } // End of impl OllamaPullerApp
 
+/// Orders models for the Modified column. Unparseable modification dates sort last in either direction,
+/// by their raw text then name; parsed dates follow `sort_dir`.
+fn compare_modified(a: &OllamaModel, b: &OllamaModel, sort_dir: &SortDirection) -> std::cmp::Ordering {
+    match (&a.modified_dt, &b.modified_dt) {
+        (None, None) => a.modified_at.cmp(&b.modified_at).then_with(|| a.name.cmp(&b.name)),
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (Some(a_dt), Some(b_dt)) => match sort_dir {
+            SortDirection::Ascending => a_dt.cmp(b_dt),
+            SortDirection::Descending => b_dt.cmp(a_dt),
+        },
+    }
+}
+
+/// Drops `StatusText` and `Progress` messages that a later one of the same kind replaces before the
+/// next status change (or other state message) arrives; log lines don't interrupt such a run. A big pull
+/// sends one of each per stream line, and only the latest is ever shown.
+fn coalesce_status_updates(messages: Vec<UpdateMessage>) -> Vec<UpdateMessage> {
+    let mut coalesced: Vec<UpdateMessage> = Vec::with_capacity(messages.len());
+    // Positions in `coalesced` of the current run's status text and progress, replaced in place
+    let mut status_text_index: Option<usize> = None;
+    let mut progress_index: Option<usize> = None;
+    for msg in messages {
+        let slot = match msg {
+            UpdateMessage::StatusText(_) => &mut status_text_index,
+            UpdateMessage::Progress(_) => &mut progress_index,
+            UpdateMessage::Log(_) => {
+                coalesced.push(msg);
+                continue;
+            }
+            _ => {
+                status_text_index = None;
+                progress_index = None;
+                coalesced.push(msg);
+                continue;
+            }
+        };
+        match *slot {
+            Some(index) => coalesced[index] = msg,
+            None => {
+                *slot = Some(coalesced.len());
+                coalesced.push(msg);
+            }
+        }
+    }
+    coalesced
+}
+
 // --- eframe::App Implementation ---
 
 impl App for OllamaPullerApp {
@@ -494,55 +1453,347 @@ impl App for OllamaPullerApp {
 
     /// Called on each frame to update the UI and handle events.
     fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
-        let mut trigger_refresh_after_delete = false; // Flag to refresh list after delete
+        let mut deleted_model: Option<String> = None; // Set when a delete succeeded, to refresh the list
+        let mut batch_succeeded = false; // Set when a batch pull finished successfully (for "Test after download")
         let mut needs_repaint = false; // Flag to track if repaint is needed this frame
 
         // Store previous sort/column state *before* any UI interaction or message processing
         let prev_sort_state = self.model_sort_state.clone();
         let prev_column_states = self.model_column_states.clone();
+        let prev_group_by_base = self.model_group_by_base;
+        let prev_show_only_loaded = self.show_only_loaded;
         let previous_view = self.current_view.clone(); // Store previous view
 
         // --- 1. Process MPSC Messages ---
+        // Settle a host change first, so queued results from the old host are recognized as stale
+        if self.sync_host_generation() {
+            let is_idle = matches!(
+                *self.status.lock().unwrap(),
+                AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+            );
+            if is_idle {
+                self.refresh_model_list(); // Show the new host's models (a quick edit syncs and refreshes itself)
+            }
+        }
         let mut messages_to_process = Vec::new();
         while let Ok(msg) = self.update_receiver.try_recv() {
             messages_to_process.push(msg);
         }
 
+        // Only the latest status text and progress of a burst are ever shown, so skip the rest
+        let received = messages_to_process.len();
+        let messages_to_process = coalesce_status_updates(messages_to_process);
+        if messages_to_process.len() < received {
+            trace!("Coalesced {} of {} messages this frame.", received - messages_to_process.len(), received);
+        }
         for msg in messages_to_process {
             needs_repaint = true; // Any message likely requires a repaint
             match msg {
-                UpdateMessage::Log(log_line) => {
-                    let mut logs = self.logs.lock().unwrap();
-                    logs.push(log_line);
-                    self.logs_dirty = true;
-                }
+                UpdateMessage::Log(log_line) => self.push_log_line(log_line),
                 UpdateMessage::Progress(p) => *self.progress.lock().unwrap() = p,
                 UpdateMessage::StatusText(s) => *self.status_text.lock().unwrap() = s,
                 UpdateMessage::Status(new_status) => {
                     let mut current_status_lock = self.status.lock().unwrap();
+                    // A scan taken before a pull or delete may list files that are in use now
+                    if *current_status_lock != new_status && self.maintenance_report.is_some() {
+                        debug!("Status changed, dropping the model store scan.");
+                        self.maintenance_report = None;
+                        self.maintenance_confirm_cleanup = false;
+                    }
                     // Check if a delete operation just succeeded
-                    if matches!(*current_status_lock, AppStatus::DeletingModel(_))
-                        && matches!(new_status, AppStatus::Success)
+                    if let AppStatus::DeletingModel(model_name) = &*current_status_lock {
+                        if matches!(new_status, AppStatus::Success) {
+                            deleted_model = Some(model_name.clone());
+                        }
+                    }
+                    // Poll /api/ps right away after loading a model, so it shows up as loaded
+                    if matches!(*current_status_lock, AppStatus::LoadingModel(_)) {
+                        self.last_ps_poll = None;
+                    }
+                    // Optional chime when a batch pull finishes (success or failure)
+                    if matches!(*current_status_lock, AppStatus::Pulling(_, _))
+                        && matches!(new_status, AppStatus::Success | AppStatus::Error(_))
+                        && self.settings.play_sound_on_completion
                     {
-                        trigger_refresh_after_delete = true;
+                        Self::play_completion_sound(matches!(new_status, AppStatus::Success));
                     }
+                    // Remember when a batch pull finished successfully (for auto-clearing the progress)
+                    self.batch_completed_at = if matches!(*current_status_lock, AppStatus::Pulling(_, _))
+                        && matches!(new_status, AppStatus::Success)
+                    {
+                        batch_succeeded = true;
+                        Some(Instant::now())
+                    } else {
+                        None
+                    };
                     *current_status_lock = new_status;
                 }
-                UpdateMessage::ModelList(models) => {
+                UpdateMessage::ModelList(generation, _)
+                | UpdateMessage::ModelsChanged(generation, _, _)
+                | UpdateMessage::RunningModels(generation, _)
+                | UpdateMessage::ModelDetails(generation, _, _)
+                | UpdateMessage::PullDigestNotes(generation, _)
+                | UpdateMessage::ModelUpdateStates(generation, _)
+                | UpdateMessage::ReclaimableSizes(generation, _)
+                    if generation != self.host_generation =>
+                {
+                    debug!("Ignoring a result from a previous Ollama host (generation {}).", generation);
+                }
+                UpdateMessage::ModelList(_, models) => {
+                    // Drop selections and cached details for models that no longer exist
+                    self.selected_models
+                        .retain(|name| models.iter().any(|m| &m.name == name));
+                    if let Some(highlighted) = &self.highlighted_model {
+                        if !models.iter().any(|m| &m.name == highlighted) {
+                            self.highlighted_model = None;
+                        }
+                    }
+                    self.model_details.clear(); // Details may be stale after a pull
                     *self.listed_models.lock().unwrap() = models;
+                    self.last_model_list_at = Some(Utc::now());
+                    self.update_model_store_space();
                     self.manage_view_cache_dirty = true; // Mark cache dirty when list updates
                 }
+                UpdateMessage::ModelsChanged(_, updated, removed) => {
+                    self.merge_model_changes(updated, removed);
+                }
+                UpdateMessage::PullDigestNotes(_, notes) => {
+                    self.pull_digest_notes = notes;
+                }
+                UpdateMessage::BatchFailures(failed) => {
+                    self.failed_models = failed;
+                }
+                UpdateMessage::BatchCancelled(cancelled) => {
+                    self.cancelled_models = cancelled;
+                }
+                UpdateMessage::ModelDetails(_, model_name, result) => {
+                    self.model_details_pending.remove(&model_name);
+                    self.model_details.insert(model_name, result);
+                    self.manage_view_cache_dirty = true; // For the Base / Fine-tune and Context columns
+                }
+                UpdateMessage::ServerVersion(host, version) => {
+                    // Ignore answers from a host that is no longer configured
+                    if host == self.settings.ollama_host {
+                        info!("Ollama server version: {}", version);
+                        if let Some(warning) = ollama::version_compatibility_warning(&version) {
+                            warn!("{}", warning);
+                        }
+                        self.server_version = Some(version);
+                    }
+                }
+                UpdateMessage::LatestRelease(version, url) => {
+                    if update_check::is_newer_than_current(&version) {
+                        info!("A newer {} release is available: v{} ({})", APP_NAME, version, url);
+                    } else {
+                        debug!("{} is up to date (latest release: v{}).", APP_NAME, version);
+                    }
+                    self.last_update_check = Some(Utc::now());
+                    self.latest_release = Some((version, url));
+                    self.save_settings();
+                }
+                UpdateMessage::ReclaimableSizes(_, result) => {
+                    self.reclaimable_sizes_running = false;
+                    match result {
+                        Ok(sizes) => {
+                            let total: u64 = sizes.values().sum();
+                            let _ = self.task_update_sender.send(UpdateMessage::Log(format!(
+                                "INFO: Computed reclaimable sizes for {} model(s) ({} not shared with other models).",
+                                sizes.len(),
+                                format_size(total)
+                            )));
+                            self.reclaimable_sizes = Some(sizes);
+                        }
+                        Err(e) => {
+                            error!("Failed to compute reclaimable sizes: {}", e);
+                            let _ = self
+                                .task_update_sender
+                                .send(UpdateMessage::Log(format!("ERROR: Failed to compute reclaimable sizes: {}", e)));
+                            self.reclaimable_sizes = None;
+                        }
+                    }
+                    self.manage_view_cache_dirty = true;
+                }
+                UpdateMessage::ModelUpdateStates(_, results) => {
+                    let updates: Vec<&str> = results
+                        .iter()
+                        .filter(|(_, _, state)| *state == ModelUpdateState::UpdateAvailable)
+                        .map(|(name, _, _)| name.as_str())
+                        .collect();
+                    let unknown = results.iter().filter(|(_, _, state)| *state == ModelUpdateState::Unknown).count();
+                    let summary = if updates.is_empty() {
+                        format!("Model update check: no updates found ({} model(s) not checkable).", unknown)
+                    } else {
+                        format!(
+                            "Model update check: {} update(s) available: {} ({} model(s) not checkable).",
+                            updates.len(),
+                            updates.join(", "),
+                            unknown
+                        )
+                    };
+                    info!("{}", summary);
+                    let _ = self.task_update_sender.send(UpdateMessage::Log(format!("INFO: {}", summary)));
+                    self.model_update_states = results
+                        .into_iter()
+                        .map(|(name, digest, state)| (name, (digest, state)))
+                        .collect();
+                    self.model_update_check_running = false;
+                    self.manage_view_cache_dirty = true;
+                }
+                UpdateMessage::DiagnosticStep(run_id, step) => {
+                    if run_id == self.diagnostics_run {
+                        match &step.result {
+                            Ok(detail) => debug!("Diagnostics: {} passed in {} ms: {}", DIAGNOSTIC_STEPS[step.index], step.elapsed_ms, detail),
+                            Err(detail) => warn!("Diagnostics: {} failed after {} ms: {}", DIAGNOSTIC_STEPS[step.index], step.elapsed_ms, detail),
+                        }
+                        self.diagnostic_steps.push(step);
+                    }
+                }
+                UpdateMessage::RemoteCommand(command, reply) => {
+                    let _ = reply.send(self.handle_remote_command(command)); // The client may have gone
+                }
+                UpdateMessage::WorkbenchOutput(output) => self.workbench.output = Some(output),
+                UpdateMessage::WorkbenchToken(token) => {
+                    if let Some(Ok(response)) = &mut self.workbench.output {
+                        response.push_str(&token);
+                    }
+                }
+                UpdateMessage::WorkbenchTokensPerSec(rate) => self.workbench.tokens_per_sec = Some(rate),
+                UpdateMessage::RunningModels(_, names) => {
+                    let now = Utc::now();
+                    for name in &names {
+                        self.model_last_used.insert(name.clone(), now);
+                    }
+                    let running: HashSet<String> = names.into_iter().collect();
+                    // Persist only when the loaded set changes, not on every poll
+                    if running != self.running_models {
+                        debug!("Running models changed: {:?}", running);
+                        self.running_models = running;
+                        self.save_settings();
+                    }
+                    self.manage_view_cache_dirty = true;
+                }
+            }
+        }
+
+        // --- 1a2. Track Idleness (pauses background polling) ---
+        let has_interaction = ctx.input(|i| i.focused && !i.events.is_empty());
+        if has_interaction {
+            self.last_interaction = Instant::now();
+            if self.background_polling_paused {
+                self.background_polling_paused = false;
+                info!("Activity detected, resuming background polling and refreshing.");
+                // Re-sync right away, things may have changed while paused
+                self.last_ps_poll = None;
+                let is_idle = matches!(
+                    *self.status.lock().unwrap(),
+                    AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+                );
+                if is_idle {
+                    self.refresh_model_list();
+                }
+            }
+        } else if !self.background_polling_paused && self.settings.idle_pause_minutes > 0 {
+            let idle_threshold = Duration::from_secs(self.settings.idle_pause_minutes * 60);
+            if self.last_interaction.elapsed() >= idle_threshold {
+                self.background_polling_paused = true;
+                info!(
+                    "No activity for {} minutes, pausing background polling until the window is used again.",
+                    self.settings.idle_pause_minutes
+                );
+            }
+        }
+
+        // --- 1b. Poll Running Models (Last Used Tracking) ---
+        if !self.background_polling_paused {
+            let ps_poll_interval = Duration::from_secs(PS_POLL_INTERVAL_SECS);
+            let ps_poll_due = match self.last_ps_poll {
+                Some(last_poll) => last_poll.elapsed() >= ps_poll_interval,
+                None => true,
+            };
+            if ps_poll_due {
+                self.poll_running_models();
+            }
+            // Wake up for the next poll even if nothing else happens
+            ctx.request_repaint_after(ps_poll_interval);
+        }
+
+        // --- 1b2. Apply Window Level (on startup, toggle or config reload) ---
+        if self.applied_always_on_top != Some(self.settings.always_on_top) {
+            let level = if self.settings.always_on_top {
+                egui::WindowLevel::AlwaysOnTop
+            } else {
+                egui::WindowLevel::Normal
+            };
+            // Silently ignored where unsupported (e.g. some Wayland compositors)
+            debug!("Setting window level: {:?}", level);
+            ctx.send_viewport_cmd(ViewportCommand::WindowLevel(level));
+            self.applied_always_on_top = Some(self.settings.always_on_top);
+        }
+
+        // --- 1c. Check Server Version (on startup and after host changes) ---
+        if self.version_checked_host.as_deref() != Some(self.settings.ollama_host.as_str()) {
+            self.check_server_version();
+        }
+
+        // --- 1c2. Check for a LlamaLift Update and for Model Updates (opt-in, at most daily) ---
+        self.check_for_app_update();
+        self.check_model_updates(false);
+
+        // --- 1c3. Start/Stop the Remote API (on startup and settings changes) ---
+        self.sync_remote_api(ctx);
+
+        // --- 1d. Auto-Clear Finished Batch Progress ---
+        if let Some(completed_at) = self.batch_completed_at {
+            let clear_delay = Duration::from_secs(BATCH_PROGRESS_CLEAR_DELAY_SECS);
+            if !self.settings.auto_clear_batch_progress {
+                self.batch_completed_at = None;
+            } else if completed_at.elapsed() >= clear_delay {
+                let mut status_lock = self.status.lock().unwrap();
+                // Only clear if nothing else happened since (errors stay visible)
+                if *status_lock == AppStatus::Success {
+                    debug!("Clearing finished batch progress.");
+                    *status_lock = AppStatus::Idle;
+                    *self.status_text.lock().unwrap() = "Idle".to_string();
+                    *self.progress.lock().unwrap() = 0.0;
+                    needs_repaint = true;
+                }
+                self.batch_completed_at = None;
+            } else {
+                ctx.request_repaint_after(clear_delay - completed_at.elapsed());
             }
         }
 
         // --- 2. Handle Triggered Refresh ---
-        if trigger_refresh_after_delete {
-            info!("Delete succeeded, triggering model list refresh.");
-            self.refresh_model_list();
+        if let Some(model_name) = deleted_model {
+            let is_listed = self.listed_models.lock().unwrap().iter().any(|m| m.name == model_name);
+            if self.settings.per_model_refresh && is_listed {
+                debug!("Delete succeeded, removing '{}' from the model list.", model_name);
+                self.merge_model_changes(Vec::new(), vec![model_name]);
+            } else {
+                info!("Delete succeeded, triggering model list refresh.");
+                self.refresh_model_list();
+            }
             *self.status_text.lock().unwrap() = "Model list updated.".to_string();
             needs_repaint = true;
         }
 
+        // --- 2b. Open the Workbench to Test a Freshly Pulled Model ---
+        if batch_succeeded && self.settings.test_after_download {
+            // Failed models are not in the list of a successful batch, but skip them to be safe (and cancelled ones)
+            let first_pulled = self
+                .batch_models
+                .iter()
+                .find(|name| !self.failed_models.contains(name) && !self.cancelled_models.contains(name))
+                .map(|name| with_default_tag(name)); // The name as Ollama lists it
+            if let Some(model_name) = first_pulled {
+                info!("Opening the Prompt Workbench to test '{}'.", model_name);
+                self.workbench.prepare_test(&model_name);
+                self.current_view = AppView::Workbench;
+                *self.status_text.lock().unwrap() = format!("Pulled. Press Test to try {}.", model_name);
+                needs_repaint = true;
+            }
+        }
+
         // --- 3. Handle View Switch ---
         // Check if view switched *before* drawing UI
         if self.current_view != previous_view {
@@ -555,6 +1806,54 @@ impl App for OllamaPullerApp {
             }
         }
 
+        // --- 3b. Keyboard Shortcuts ---
+        // Ignore shortcuts while a text field has focus
+        let mut force_logs_open: Option<bool> = None;
+        if !ctx.wants_keyboard_input()
+            && ctx.input_mut(|i| i.consume_shortcut(&TOGGLE_LOGS_SHORTCUT))
+        {
+            self.logs_collapsed = !self.logs_collapsed;
+            force_logs_open = Some(!self.logs_collapsed);
+            debug!("Log panel toggled via shortcut (collapsed: {}).", self.logs_collapsed);
+            needs_repaint = true;
+        }
+        // Surface failures: expand the log panel when the status turns into an error. Checked per frame,
+        // so errors set directly by the views (e.g. invalid input) are noticed too, one frame later.
+        let status_is_error = matches!(*self.status.lock().unwrap(), AppStatus::Error(_));
+        // Show the Manage view's error notice again for a new error
+        self.error_toast_dismissed = views::manage_models_view::error_toast_dismissed_after(
+            self.error_toast_dismissed,
+            self.status_was_error,
+            status_is_error,
+        );
+        if status_is_error && !self.status_was_error && self.settings.expand_logs_on_error {
+            if self.logs_collapsed {
+                debug!("Expanding the log panel after an error.");
+                self.logs_collapsed = false;
+                force_logs_open = Some(true);
+            }
+            self.scroll_logs_to_newest = true;
+            needs_repaint = true;
+        }
+        self.status_was_error = status_is_error;
+        // Quick open also works from a text field, Ctrl/Cmd+P doesn't type anything
+        if ctx.input_mut(|i| i.consume_shortcut(&QUICK_OPEN_SHORTCUT)) {
+            self.show_quick_open = !self.show_quick_open;
+            debug!("Quick open toggled via shortcut (open: {}).", self.show_quick_open);
+            needs_repaint = true;
+        }
+        // These only open their window (closing is done in the window), so a held key can't flicker it
+        if ctx.input_mut(|i| i.consume_shortcut(&SETTINGS_SHORTCUT)) && !self.show_settings_window {
+            debug!("Settings opened via shortcut.");
+            self.show_settings_window = true;
+            needs_repaint = true;
+        }
+        if ctx.input_mut(|i| i.consume_shortcut(&ABOUT_SHORTCUT)) && !self.show_about_window {
+            debug!("About opened via shortcut.");
+            self.show_about_window = true;
+            needs_repaint = true;
+        }
+
         // --- 4. Rebuild Log Cache ---
         self.rebuild_log_cache(); // Rebuild log cache if necessary
 
@@ -589,60 +1888,251 @@ impl App for OllamaPullerApp {
         TopBottomPanel::top("top_panel").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
                 ui.menu_button("File", |ui| {
-                    if ui.button("Settings").clicked() {
+                    if ui
+                        .add(egui::Button::new("Settings").shortcut_text(ctx.format_shortcut(&SETTINGS_SHORTCUT)))
+                        .clicked()
+                    {
                         self.show_settings_window = true;
                         needs_repaint = true;
                         ui.close_menu();
                     }
+                    if ui
+                        .button("Reload Config from Disk")
+                        .on_hover_text("Re-read the config file, e.g. after editing it by hand")
+                        .clicked()
+                    {
+                        // Ask first if reloading would throw away unsaved in-app changes
+                        if self.has_unsaved_settings() {
+                            self.show_reload_config_confirm = true;
+                        } else {
+                            self.reload_settings_from_disk();
+                        }
+                        needs_repaint = true;
+                        ui.close_menu();
+                    }
+                    if ui
+                        .button("Maintenance")
+                        .on_hover_text("Find partial downloads, unreferenced blobs and incomplete models in the local model store")
+                        .clicked()
+                    {
+                        self.show_maintenance_window = true;
+                        self.detected_models_dir = config::detect_models_dir();
+                        self.maintenance_models_dir =
+                            config::resolve_models_dir(&self.settings, self.detected_models_dir.as_deref());
+                        needs_repaint = true;
+                        ui.close_menu();
+                    }
                     ui.separator();
                     if ui.button("Quit").clicked() {
                         ctx.send_viewport_cmd(ViewportCommand::Close);
                     }
                 });
+                ui.menu_button("View", |ui| {
+                    if ui
+                        .checkbox(&mut self.settings.always_on_top, "Always on Top")
+                        .on_hover_text("Keep this window above others, e.g. to watch pull progress. Not supported on every platform (e.g. some Wayland compositors).")
+                        .changed()
+                    {
+                        info!("Always on top set to {}.", self.settings.always_on_top);
+                        self.save_settings();
+                        needs_repaint = true;
+                        ui.close_menu();
+                    }
+                    if ui
+                        .checkbox(&mut self.settings.logs_newest_first, "Newest Logs First")
+                        .on_hover_text("Show the newest log lines at the top instead of the bottom.")
+                        .changed()
+                    {
+                        self.logs_dirty = true; // Rebuild the log text in the new order
+                        self.save_settings();
+                        needs_repaint = true;
+                        ui.close_menu();
+                    }
+                });
                 ui.menu_button("Help", |ui| {
                     if ui.button("Copy Logs").clicked() {
                         self.copy_logs_requested = true;
                         needs_repaint = true;
                         ui.close_menu();
                     }
+                    // Recovery action, only offered while an operation is (supposedly) running
+                    let current_status = self.status.lock().unwrap().clone();
+                    if !matches!(current_status, AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)) {
+                        ui.separator();
+                        if ui
+                            .button("⚠ Reset Stuck State")
+                            .on_hover_text(format!(
+                                "Recovery only: forces the status ({:?}) back to Idle if the app stays busy with nothing running. A task that is still running keeps going in the background.",
+                                current_status
+                            ))
+                            .clicked()
+                        {
+                            self.reset_stuck_state(current_status);
+                            needs_repaint = true;
+                            ui.close_menu();
+                        }
+                    }
+                    if ui
+                        .button("Connection Diagnostics")
+                        .on_hover_text("Check step by step (DNS, TCP, API) why the Ollama server can't be reached")
+                        .clicked()
+                    {
+                        self.show_diagnostics = true;
+                        self.run_diagnostics();
+                        needs_repaint = true;
+                        ui.close_menu();
+                    }
+                    if ui
+                        .button("Effective Configuration")
+                        .on_hover_text("The configuration as the app actually uses it (settings, environment, fallbacks), for support requests")
+                        .clicked()
+                    {
+                        self.effective_config_text = config::effective_config_json(&self.settings, self.config_path.as_deref());
+                        self.show_effective_config = true;
+                        needs_repaint = true;
+                        ui.close_menu();
+                    }
                     ui.separator();
-                    if ui.button("About").clicked() {
+                    if ui
+                        .add(egui::Button::new("About").shortcut_text(ctx.format_shortcut(&ABOUT_SHORTCUT)))
+                        .clicked()
+                    {
                         self.show_about_window = true;
                         needs_repaint = true;
                         info!("About button clicked - {} v{}", APP_NAME, SCRIPT_VERSION);
                         ui.close_menu();
                     }
                 });
+                // Non-intrusive update note, only if the opt-in check found a newer release
+                if let Some((version, url)) = self.available_update() {
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.hyperlink_to(format!("⬆ Update available (v{})", version), url)
+                            .on_hover_text("Opens the release page on GitHub.");
+                    });
+                }
             });
             ui.add_space(4.0);
             // View Selection - Allow interaction, state change handled in step 3
             ui.horizontal(|ui| {
                 ui.selectable_value(&mut self.current_view, AppView::Download, "Download Models");
-                ui.selectable_value(&mut self.current_view, AppView::ManageModels, "Manage Models");
+                ui.selectable_value(&mut self.current_view, AppView::ManageModels, "Manage Models")
+                    .on_hover_ui(|ui| {
+                        views::manage_models_view::draw_manage_stats_tooltip(self, ui)
+                    });
+                ui.selectable_value(&mut self.current_view, AppView::Workbench, "Prompt Workbench")
+                    .on_hover_text("Advanced: test a system prompt on a model and save it as a new model");
+                // Quick host switch (applied on Enter; unapplied edits are dropped on blur)
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let host_response = ui.add(
+                        egui::TextEdit::singleline(&mut self.host_quick_edit)
+                            .desired_width(180.0)
+                            .hint_text("host:port"),
+                    )
+                    .on_hover_text("Ollama host. Press Enter to switch and refresh.");
+                    ui.label("Host:");
+                    // Keep it visible that deletes happen without asking
+                    if !self.settings.confirm_deletes {
+                        let badge = ui
+                            .add(
+                                egui::Button::new(
+                                    egui::RichText::new("⚠ Delete confirmations off")
+                                        .small()
+                                        .color(ui.visuals().warn_fg_color),
+                                )
+                                .small(),
+                            )
+                            .on_hover_text("The 🗑 button deletes models right away. Click to open Settings and turn confirmations back on.");
+                        if badge.clicked() {
+                            self.show_settings_window = true;
+                            needs_repaint = true;
+                        }
+                    }
+                    if host_response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        self.apply_quick_host();
+                        needs_repaint = true;
+                    }
+                    if !host_response.has_focus() {
+                        self.host_quick_edit = self.settings.ollama_host.clone();
+                    }
+                });
             });
+            // Security nudge: plain HTTP to a remote host is unencrypted
+            if !self.http_warning_dismissed && is_insecure_remote_host(&self.settings.ollama_host) {
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    ui.colored_label(
+                        ui.visuals().warn_fg_color,
+                        format!(
+                            "⚠ Connected to '{}' over plain HTTP: traffic is unencrypted.",
+                            self.settings.ollama_host
+                        ),
+                    )
+                    .on_hover_text("Consider an HTTPS reverse proxy or a VPN/SSH tunnel when Ollama is exposed over a network.");
+                    if ui.small_button("Dismiss").clicked() {
+                        info!("Plain HTTP warning dismissed for this session.");
+                        self.http_warning_dismissed = true;
+                        needs_repaint = true;
+                    }
+                });
+            }
+            // Compatibility nudge: the server version is known to behave differently
+            let version_warning = self
+                .server_version
+                .as_deref()
+                .and_then(ollama::version_compatibility_warning);
+            if let Some(warning) = version_warning.filter(|_| !self.version_warning_dismissed) {
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", warning))
+                        .on_hover_text("Consider updating Ollama if you run into parse or progress issues.");
+                    if ui.small_button("Dismiss").clicked() {
+                        info!("Server version warning dismissed.");
+                        self.version_warning_dismissed = true;
+                        needs_repaint = true;
+                    }
+                });
+            }
             ui.add_space(4.0);
             ui.add(Separator::default().spacing(0.0));
         });
 
         // Draw Bottom Panel (Logs)
-        TopBottomPanel::bottom("log_panel")
+        // Collapsed, it is just its header and the main view fills the window. Expanded, the main view
+        // (e.g. the models table) takes its natural height and the logs get what it leaves empty, so a
+        // short list in a tall window doesn't leave a gap above a small log panel.
+        let mut log_panel = TopBottomPanel::bottom("log_panel")
             .resizable(true)
-            .show_separator_line(true)
-            .show(ctx, |ui| {
-                let header_response = CollapsingHeader::new("Logs")
-                    .default_open(!self.logs_collapsed)
-                    .show(ui, |ui| {
-                        widgets::draw_log_view_content(self, ui);
-                    });
-                // Update collapsed state based on interaction
-                if header_response.header_response.clicked() {
-                    self.logs_collapsed = header_response.body_returned.is_none();
-                    needs_repaint = true;
-                }
-                header_response
-                    .header_response
-                    .on_hover_text("Click to expand/collapse logs");
-            });
+            .show_separator_line(true);
+        if !self.logs_collapsed {
+            let max_height = (ctx.available_rect().height() * LOG_PANEL_MAX_SHARE).max(LOG_PANEL_MIN_HEIGHT);
+            // Only grow while there is spare space, so dragging the panel smaller works with a long list
+            let min_height = if self.main_view_spare_height > 1.0 {
+                self.log_panel_height + self.main_view_spare_height
+            } else {
+                LOG_PANEL_MIN_HEIGHT
+            };
+            log_panel = log_panel.height_range(min_height.clamp(LOG_PANEL_MIN_HEIGHT, max_height)..=max_height);
+        }
+        let log_panel_response = log_panel.show(ctx, |ui| {
+            let header_response = CollapsingHeader::new("Logs")
+                .default_open(!self.logs_collapsed)
+                .open(force_logs_open) // Only Some on the frame the shortcut was used
+                .show(ui, |ui| {
+                    widgets::draw_log_view_content(self, ui);
+                });
+            // Update collapsed state based on interaction
+            if header_response.header_response.clicked() {
+                self.logs_collapsed = header_response.body_returned.is_none();
+                needs_repaint = true;
+            }
+            header_response
+                .header_response
+                .on_hover_text(format!(
+                    "Click to expand/collapse logs ({})",
+                    ctx.format_shortcut(&TOGGLE_LOGS_SHORTCUT)
+                ));
+        });
+        self.log_panel_height = log_panel_response.response.rect.height();
 
         // Draw Central Panel (Main View Content)
         // This is where manage_models_view might update sort state or column widths
@@ -655,7 +2145,20 @@ impl App for OllamaPullerApp {
                     // Cache rebuild happens inside draw_manage_models_view if needed
                     views::manage_models_view::draw_manage_models_view(self, ui, &current_status);
                 }
+                AppView::Workbench => {
+                    views::workbench_view::draw_workbench_view(self, ui, &current_status);
+                }
             }
+            // Show a busy cursor over the main view while an operation runs (menus and logs stay normal)
+            if is_busy && ui.ui_contains_pointer() {
+                ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Progress);
+            }
+            // Let the expanded log panel take up what the view left empty (applied next frame)
+            let spare_height = ui.available_height().max(0.0);
+            if !self.logs_collapsed && (spare_height - self.main_view_spare_height).abs() > 1.0 {
+                ctx.request_repaint();
+            }
+            self.main_view_spare_height = spare_height;
         });
 
         // Draw Modals / Separate Windows *after* main panels
@@ -665,10 +2168,54 @@ impl App for OllamaPullerApp {
             if self.pending_settings.is_none() {
                 info!("Settings window opened, cloning current settings to pending state.");
                 self.pending_settings = Some(self.settings.clone());
+                self.detected_models_dir = config::detect_models_dir();
+                self.new_alias_name.clear();
+                self.new_alias_target.clear();
+                self.new_header_name.clear();
+                self.new_header_value.clear();
             }
             windows::settings_window::draw_settings_window(self, ctx);
             if !self.show_settings_window { needs_repaint = true; }
         }
+        if self.show_reload_config_confirm {
+            if let Some(confirmed) = windows::reload_config_window::draw_reload_config_window(self, ctx) {
+                self.show_reload_config_confirm = false;
+                if confirmed {
+                    self.reload_settings_from_disk();
+                } else {
+                    info!("Config reload cancelled by user.");
+                }
+                needs_repaint = true;
+            }
+        }
+        if self.show_runtime_error_window {
+            windows::runtime_error_window::draw_runtime_error_window(self, ctx);
+            if !self.show_runtime_error_window { needs_repaint = true; }
+        }
+        if self.show_compare_window {
+            windows::compare_models_window::draw_compare_models_window(self, ctx);
+            if !self.show_compare_window { needs_repaint = true; }
+        }
+        if self.show_diagnostics {
+            windows::diagnostics_window::draw_diagnostics_window(self, ctx);
+            if !self.show_diagnostics { needs_repaint = true; }
+        }
+        if self.info_model.is_some() {
+            windows::model_info_window::draw_model_info_window(self, ctx);
+            if self.info_model.is_none() { needs_repaint = true; }
+        }
+        if self.show_maintenance_window {
+            windows::maintenance_window::draw_maintenance_window(self, ctx);
+            if !self.show_maintenance_window { needs_repaint = true; }
+        }
+        if self.show_quick_open {
+            windows::quick_open_window::draw_quick_open_window(self, ctx);
+            if !self.show_quick_open { needs_repaint = true; }
+        }
+        if self.show_effective_config {
+            windows::effective_config_window::draw_effective_config_window(self, ctx);
+            if !self.show_effective_config { needs_repaint = true; }
+        }
         if self.show_about_window {
             windows::about_window::draw_about_window(self, ctx);
              if !self.show_about_window { needs_repaint = true; }
@@ -687,7 +2234,37 @@ impl App for OllamaPullerApp {
                 }
             }
         }
-        // Handle Delete Confirmation Modal last
+        if self.pending_pull_confirmation.is_some() {
+            match windows::pull_confirmation_window::draw_pull_confirmation_window(self, ctx) {
+                Some(true) => {
+                    if let Some(models_to_pull) = self.pending_pull_confirmation.take() {
+                        views::download_view::start_batch_pull(self, models_to_pull);
+                    }
+                    needs_repaint = true;
+                }
+                Some(false) => {
+                    info!("Download cancelled by user.");
+                    let _ = self.task_update_sender.send(UpdateMessage::Log(
+                        "INFO: Download cancelled by user.".to_string(),
+                    ));
+                    self.pending_pull_confirmation = None;
+                    needs_repaint = true;
+                }
+                None => {}
+            }
+        }
+        if let Some(model_to_load_name) = self.model_to_load.take() {
+            self.trigger_warm_model(&model_to_load_name);
+            needs_repaint = true;
+        }
+        // Handle Delete Confirmation Modal last (skipped entirely if confirmations are turned off)
+        if !self.settings.confirm_deletes {
+            if let Some(model_to_delete_name) = self.model_to_delete.take() {
+                info!("Deleting '{}' without confirmation (turned off in Settings).", model_to_delete_name);
+                self.trigger_delete_model(&model_to_delete_name);
+                needs_repaint = true;
+            }
+        }
         let delete_confirmation_result =
             windows::delete_confirmation_window::draw_delete_confirmation_window(self, ctx);
         if delete_confirmation_result.is_some() { needs_repaint = true; }
@@ -718,6 +2295,21 @@ impl App for OllamaPullerApp {
             needs_repaint = true; // Ensure repaint happens
         }
 
+        if self.model_group_by_base != prev_group_by_base {
+            debug!("Grouping mode changed to {} after drawing UI.", self.model_group_by_base);
+            self.save_settings();
+            needs_repaint = true;
+        }
+
+        if self.show_only_loaded != prev_show_only_loaded {
+            debug!("'Only loaded' filter changed to {} after drawing UI.", self.show_only_loaded);
+            if self.settings.remember_filters {
+                self.save_settings();
+            }
+            self.manage_view_cache_dirty = true;
+            needs_repaint = true;
+        }
+
         if self.model_column_states != prev_column_states {
              // Check if it was *only* width that changed, or if visibility/order also changed
              let visibility_changed = self.model_column_states.iter().map(|cs| (&cs.column, cs.visible)).collect::<Vec<_>>() !=
@@ -736,10 +2328,75 @@ impl App for OllamaPullerApp {
         }
 
         // --- 8. Final Repaint Request ---
-        if needs_repaint || is_busy {
+        // Keep polling the channel while background detail fetches are outstanding
+        if needs_repaint || is_busy || !self.model_details_pending.is_empty() {
              ctx.request_repaint();
         }
 
     } // End of update function
 } // End of impl App
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model_modified_at(name: &str, modified_at: &str) -> OllamaModel {
+        OllamaModel {
+            name: name.to_string(),
+            modified_at: modified_at.to_string(),
+            modified_dt: chrono::DateTime::parse_from_rfc3339(modified_at).ok(),
+            ..Default::default()
+        }
+    }
+
+    fn sorted_names(models: &[OllamaModel], sort_dir: SortDirection) -> Vec<String> {
+        let mut models = models.to_vec();
+        models.sort_by(|a, b| compare_modified(a, b, &sort_dir));
+        models.into_iter().map(|m| m.name).collect()
+    }
+
+    #[test]
+    fn coalescing_keeps_the_latest_status_text_and_progress_of_each_run() {
+        let messages = vec![
+            UpdateMessage::StatusText("pulling manifest".to_string()),
+            UpdateMessage::Progress(0.1),
+            UpdateMessage::Log("INFO: [llama3] pulling manifest".to_string()),
+            UpdateMessage::Progress(0.2),
+            UpdateMessage::StatusText("pulling 6a0746a1ec1a".to_string()),
+            UpdateMessage::Progress(0.3),
+            UpdateMessage::Status(AppStatus::Pulling(2, 2)),
+            UpdateMessage::Progress(0.0),
+            UpdateMessage::Progress(1.0),
+        ];
+        let coalesced: Vec<String> = coalesce_status_updates(messages).iter().map(|m| format!("{:?}", m)).collect();
+        assert_eq!(
+            coalesced,
+            [
+                r#"StatusText("pulling 6a0746a1ec1a")"#,
+                "Progress(0.3)",
+                r#"Log("INFO: [llama3] pulling manifest")"#,
+                "Status(Pulling(2, 2))",
+                "Progress(1.0)",
+            ]
+        );
+    }
+
+    #[test]
+    fn unparseable_modified_dates_sort_last_in_both_directions() {
+        let models = [
+            model_modified_at("broken-b", "not a date"),
+            model_modified_at("new", "2024-06-01T10:00:00Z"),
+            model_modified_at("broken-a", "not a date"),
+            model_modified_at("garbled", "2024-13-99"),
+            model_modified_at("old", "2024-01-01T10:00:00+02:00"),
+        ];
+        assert_eq!(
+            sorted_names(&models, SortDirection::Ascending),
+            ["old", "new", "garbled", "broken-a", "broken-b"]
+        );
+        assert_eq!(
+            sorted_names(&models, SortDirection::Descending),
+            ["new", "old", "garbled", "broken-a", "broken-b"]
+        );
+    }
+}
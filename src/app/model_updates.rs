@@ -0,0 +1,129 @@
+// src/app/model_updates.rs
+// Optional check whether newer versions of the installed tags exist on the public Ollama registry.
+// Asks the registry for each tag's manifest digest (a HEAD request, nothing is downloaded) and compares
+// it with the local digest from `/api/tags`. Models from other registries or not on the registry
+// (custom/created models) get no verdict, so they never show a badge.
+
+use crate::app::config::{APP_NAME, SCRIPT_VERSION};
+use futures_util::{stream, StreamExt};
+use log::{debug, trace};
+use std::time::Duration;
+
+/// The public registry Ollama pulls from when a model name has no registry host.
+const REGISTRY_URL: &str = "https://registry.ollama.ai";
+const REGISTRY_HOST: &str = "registry.ollama.ai";
+
+/// Minimum time between two automatic checks (the button always checks).
+pub const MODEL_UPDATE_CHECK_INTERVAL_HOURS: i64 = 24;
+
+/// Registry requests running at the same time.
+const MAX_CONCURRENT_CHECKS: usize = 4;
+
+/// The outcome of checking one model against the registry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModelUpdateState {
+    UpToDate,
+    UpdateAvailable,
+    /// Not checkable (other registry, not found, network error); the reason is logged at debug level.
+    Unknown,
+}
+
+/// Splits a model name into its registry repository and tag, e.g. `llama3` -> (`library/llama3`,
+/// `latest`), `team/model:q4` -> (`team/model`, `q4`). Returns None for models from other registries.
+pub fn registry_reference(model_name: &str) -> Option<(String, String)> {
+    let (repository, tag) = match model_name.rsplit_once(':') {
+        Some((repository, tag)) if !tag.contains('/') => (repository, tag),
+        _ => (model_name, "latest"),
+    };
+    let mut parts: Vec<&str> = repository.split('/').collect();
+    // A first segment with a dot or port is a registry host (`hf.co/...`, `registry.example.com:5000/...`)
+    if parts.len() > 1 && (parts[0].contains('.') || parts[0].contains(':')) {
+        if parts[0] != REGISTRY_HOST {
+            return None;
+        }
+        parts.remove(0);
+    }
+    match parts.as_slice() {
+        [model] => Some((format!("library/{}", model), tag.to_string())),
+        [namespace, model] => Some((format!("{}/{}", namespace, model), tag.to_string())),
+        _ => None,
+    }
+}
+
+/// Checks the given models (name, local digest) against the registry, a few at a time.
+pub async fn check_models_async(models: Vec<(String, String)>) -> Vec<(String, String, ModelUpdateState)> {
+    let client = match reqwest::Client::builder()
+        .user_agent(format!("{}/{}", APP_NAME, SCRIPT_VERSION))
+        .timeout(Duration::from_secs(15))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            debug!("Failed to create HTTP client for the model update check: {}", e);
+            return models
+                .into_iter()
+                .map(|(name, digest)| (name, digest, ModelUpdateState::Unknown))
+                .collect();
+        }
+    };
+    stream::iter(models)
+        .map(|(name, local_digest)| {
+            let client = client.clone();
+            async move {
+                let state = match registry_reference(&name) {
+                    Some((repository, tag)) => check_model(&client, &repository, &tag, &local_digest).await,
+                    None => {
+                        debug!("Not checking '{}' for updates: not from {}.", name, REGISTRY_HOST);
+                        ModelUpdateState::Unknown
+                    }
+                };
+                (name, local_digest, state)
+            }
+        })
+        .buffer_unordered(MAX_CONCURRENT_CHECKS)
+        .collect()
+        .await
+}
+
+// Compares one tag's manifest digest on the registry with the local digest.
+async fn check_model(client: &reqwest::Client, repository: &str, tag: &str, local_digest: &str) -> ModelUpdateState {
+    let url = format!("{}/v2/{}/manifests/{}", REGISTRY_URL, repository, tag);
+    trace!("Sending manifest HEAD request to {}", url);
+    let res = match client
+        .head(&url)
+        .header(reqwest::header::ACCEPT, "application/vnd.docker.distribution.manifest.v2+json")
+        .send()
+        .await
+    {
+        Ok(res) => res,
+        Err(e) => {
+            debug!("Update check for {}:{} failed: {}", repository, tag, e);
+            return ModelUpdateState::Unknown;
+        }
+    };
+    if !res.status().is_success() {
+        // 404 for models that only exist locally (created or copied under a new name)
+        debug!("Update check for {}:{}: registry returned {}", repository, tag, res.status());
+        return ModelUpdateState::Unknown;
+    }
+    // The manifest digest, which is what /api/tags reports as the model's digest
+    let remote_digest = ["docker-content-digest", "etag"].iter().find_map(|header| {
+        res.headers()
+            .get(*header)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim_matches('"').trim_start_matches("sha256:").to_lowercase())
+    });
+    // Models listed via `ollama list` only have the first 12 characters of the digest
+    let local_digest = local_digest.trim_start_matches("sha256:").to_lowercase();
+    match remote_digest {
+        Some(remote) if !local_digest.is_empty() && remote.starts_with(&local_digest) => ModelUpdateState::UpToDate,
+        Some(remote) => {
+            debug!("{}:{} has a newer version on the registry ({}).", repository, tag, remote);
+            ModelUpdateState::UpdateAvailable
+        }
+        None => {
+            debug!("Update check for {}:{}: no digest in the registry response.", repository, tag);
+            ModelUpdateState::Unknown
+        }
+    }
+}
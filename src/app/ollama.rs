@@ -1,16 +1,24 @@
 // src/app/ollama.rs
-// Handles interactions with the Ollama API: defines request/response structs and async functions for API calls (pull, list, delete).
+// Handles interactions with the Ollama API: defines request/response structs and async functions for API calls (pull, list, running models, show, version, warm, generate (plain and streamed), create, delete).
+// Listing can optionally fall back to the `ollama list` CLI when the API is unreachable.
 
+use crate::app::catalog::known_model_size;
 use crate::app::config::Config;
-use crate::app::state::UpdateMessage;
-use crate::app::utils::format_size; 
-use chrono::{DateTime, FixedOffset}; // Used for parsing dates, Added FixedOffset
+use crate::app::state::{DiagnosticStep, UpdateMessage};
+use crate::app::utils::{format_size, parse_version, redact_url_credentials};
+use chrono::{DateTime, FixedOffset, Utc}; // Used for parsing dates, Added FixedOffset
 use chrono_tz::Tz;
 use futures_util::StreamExt;
 use log::{debug, error, trace, warn};
 use reqwest;
 use serde::{Deserialize, Serialize};
-use std::sync::mpsc::Sender;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc::Sender,
+    Arc,
+};
 
 // --- Ollama API Structures ---
 
@@ -34,8 +42,15 @@ pub struct OllamaModelDetails {
     pub quantization_level: Option<String>,
 }
 
+impl OllamaModelDetails {
+    /// Returns the families, treating a missing field, an explicit `null` and `[]` alike (no families).
+    pub fn families_list(&self) -> &[String] {
+        self.families.as_deref().unwrap_or_default()
+    }
+}
+
 /// Represents a single model returned by the `/api/tags` endpoint.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, Default)]
 pub struct OllamaModel {
     pub name: String,
     pub modified_at: String, // Original timestamp string from Ollama
@@ -54,6 +69,30 @@ pub struct OllamaModel {
     // Add parsed DateTime for sorting
     #[serde(skip)] // Don't expect this from JSON
     pub modified_dt: Option<DateTime<FixedOffset>>, // Store with original offset
+    // Last time the model was seen loaded via /api/ps (app-maintained, filled in the view cache)
+    #[serde(skip)]
+    pub last_used_dt: Option<DateTime<Utc>>,
+    #[serde(skip)]
+    pub last_used_local: Option<String>,
+    // Digest shortened per the display settings (filled in the view cache)
+    #[serde(skip)]
+    pub digest_display: String,
+    // Base / fine-tune / derived, from cached /api/show details (filled in the view cache)
+    #[serde(skip)]
+    pub lineage: Option<String>,
+    // Context window size in tokens, from cached /api/show details (filled in the view cache)
+    #[serde(skip)]
+    pub context_length: Option<u64>,
+    // What deleting only this model would free, i.e. its layers no other model uses (see
+    // maintenance::unique_model_sizes; filled in the view cache once computed)
+    #[serde(skip)]
+    pub reclaimable_size: Option<u64>,
+    // A newer version of this tag is on the registry (see model_updates, filled in the view cache)
+    #[serde(skip)]
+    pub update_available: bool,
+    // Listed by the `ollama list` fallback: short digest, rounded size and a relative modification time
+    #[serde(skip)]
+    pub listed_via_cli: bool,
 }
 
 /// Represents the overall response structure from the `/api/tags` endpoint.
@@ -62,31 +101,388 @@ pub struct OllamaTagsResponse {
     pub models: Vec<OllamaModel>,
 }
 
+/// Represents a single loaded model returned by the `/api/ps` endpoint.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OllamaRunningModel {
+    pub name: String,
+}
+
+/// Represents the overall response structure from the `/api/ps` endpoint.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OllamaPsResponse {
+    pub models: Vec<OllamaRunningModel>,
+}
+
+/// Represents the response from the `/api/show` endpoint (only the fields LlamaLift uses).
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct OllamaShowResponse {
+    pub model_info: Option<HashMap<String, serde_json::Value>>, // May be missing/null on older servers
+    pub modelfile: Option<String>,
+    pub details: Option<OllamaShowDetails>,
+}
+
+/// The `details` of an `/api/show` response (only the fields LlamaLift uses).
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct OllamaShowDetails {
+    pub parent_model: Option<String>, // Empty for models not created from another model
+}
+
+impl OllamaShowResponse {
+    /// Returns the context length, stored in `model_info` as `<architecture>.context_length`.
+    pub fn context_length(&self) -> Option<u64> {
+        self.model_info
+            .as_ref()?
+            .iter()
+            .find(|(key, _)| key.ends_with(".context_length"))
+            .and_then(|(_, value)| value.as_u64())
+    }
+
+    /// Tells base models from models built on top of another one. A heuristic: an `ADAPTER`
+    /// line in the modelfile means a (LoRA) fine-tune, a `parent_model` a derived/custom model.
+    pub fn lineage(&self) -> String {
+        let has_adapter = self.modelfile.as_deref().is_some_and(|modelfile| {
+            modelfile
+                .lines()
+                .any(|line| line.trim_start().to_uppercase().starts_with("ADAPTER "))
+        });
+        let parent_model = self
+            .details
+            .as_ref()
+            .and_then(|details| details.parent_model.as_deref())
+            .filter(|parent| !parent.is_empty());
+        match (has_adapter, parent_model) {
+            (true, _) => "Fine-tune".to_string(),
+            (false, Some(parent)) => format!("Derived ({})", parent),
+            (false, None) => "Base".to_string(),
+        }
+    }
+}
+
+/// Represents the response from the `/api/version` endpoint.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OllamaVersionResponse {
+    pub version: String,
+}
+
+/// Represents an error body returned by the Ollama API, e.g. `{"error": "model not found"}`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OllamaErrorResponse {
+    pub error: String,
+}
+
+/// Represents the request body for `/api/generate` when only loading a model (empty prompt).
+#[derive(Serialize, Debug, Clone)]
+pub struct OllamaWarmRequest {
+    pub model: String,
+    pub prompt: String,
+    pub keep_alive: String,
+    pub stream: bool,
+}
+
+/// Represents the request body for a non-streamed `/api/generate` with a system prompt and options.
+#[derive(Serialize, Debug, Clone)]
+pub struct OllamaGenerateRequest {
+    pub model: String,
+    pub system: String,
+    pub prompt: String,
+    pub options: HashMap<String, serde_json::Value>,
+    pub stream: bool,
+}
+
+/// Represents the response of `/api/generate` (only the fields LlamaLift uses). Streamed responses
+/// send one of these per token; the last one has `done` set and the evaluation stats.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OllamaGenerateResponse {
+    #[serde(default)]
+    pub response: String,
+    #[serde(default)]
+    pub done: bool,
+    /// Number of generated tokens (final response only).
+    pub eval_count: Option<u64>,
+    /// Time spent generating them, in nanoseconds (final response only).
+    pub eval_duration: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl OllamaGenerateResponse {
+    /// Generation speed in tokens per second, if the response carries the stats.
+    pub fn tokens_per_sec(&self) -> Option<f64> {
+        match (self.eval_count, self.eval_duration) {
+            (Some(count), Some(duration)) if duration > 0 => Some(count as f64 / (duration as f64 / 1e9)),
+            _ => None,
+        }
+    }
+}
+
+/// How a streamed generation ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GenerateEnd {
+    /// The model finished; contains the tokens per second, if reported.
+    Done(Option<f64>),
+    /// Stopped through the cancel flag before the model finished.
+    Cancelled,
+}
+
+/// Represents the request body for the `/api/create` endpoint (Ollama 0.5.5+ JSON form).
+#[derive(Serialize, Debug, Clone)]
+pub struct OllamaCreateRequest {
+    pub model: String,
+    pub from: String,
+    pub system: String,
+    pub parameters: HashMap<String, serde_json::Value>,
+    pub stream: bool,
+}
+
 /// Represents the request body for the `/api/delete` endpoint.
 #[derive(Serialize, Debug, Clone)]
 pub struct OllamaDeleteRequest {
     pub name: String,
 }
 
+// --- Version Compatibility ---
+
+/// Server versions known to behave differently from what LlamaLift expects.
+/// Each entry applies to servers *older* than the given (major, minor, patch) version.
+const VERSION_COMPATIBILITY_NOTES: &[((u64, u64, u64), &str)] = &[(
+    (0, 1, 38),
+    "it predates /api/ps, so loaded models and the Last Used column cannot be tracked",
+)];
+
+/// Returns a compatibility warning if the server version is known to be problematic.
+/// Unparseable versions (e.g. custom builds) produce no warning.
+pub fn version_compatibility_warning(version: &str) -> Option<String> {
+    let parsed = parse_version(version)?;
+    let notes: Vec<&str> = VERSION_COMPATIBILITY_NOTES
+        .iter()
+        .filter(|(below, _)| parsed < *below)
+        .map(|(_, note)| *note)
+        .collect();
+    if notes.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "Ollama {} is older than LlamaLift was tested with: {}.",
+        version,
+        notes.join("; ")
+    ))
+}
+
+// --- Response Helpers ---
+
+/// Returns the `error` message of a JSON body, or of the first line carrying one
+/// if the body is newline-delimited (streamed) JSON.
+pub fn find_error_in_body(body: &str) -> Option<String> {
+    if let Ok(parsed) = serde_json::from_str::<OllamaErrorResponse>(body.trim()) {
+        return Some(parsed.error);
+    }
+    body.lines()
+        .filter_map(|line| serde_json::from_str::<OllamaErrorResponse>(line.trim()).ok())
+        .map(|parsed| parsed.error)
+        .next()
+}
+
+/// Turns an error response body into a readable message: the JSON `error` field if present,
+/// otherwise the raw (trimmed) text.
+pub fn parse_error_body(body: &str) -> String {
+    let trimmed = body.trim();
+    if trimmed.is_empty() {
+        return "Unknown server error".to_string();
+    }
+    find_error_in_body(trimmed).unwrap_or_else(|| trimmed.to_string())
+}
+
+// --- HTTP Client Helpers ---
+
+/// Returns the base URL of the Ollama server for `{base}/api/...` requests: the configured host with
+/// `http://` prepended if it has no scheme, and without trailing slashes.
+fn base_url(config: &Config) -> String {
+    let host = config.ollama_host.trim_end_matches('/');
+    if host.starts_with("http://") || host.starts_with("https://") {
+        host.to_string()
+    } else {
+        format!("http://{}", host)
+    }
+}
+
+/// Builds an HTTP client with the configured redirect policy.
+/// Pulls only follow redirects if `follow_pull_redirects` is set, as streamed responses
+/// (and POST bodies on 301/302) don't survive redirects reliably.
+fn build_client(config: &Config, is_pull: bool) -> Result<reqwest::Client, String> {
+    let policy = if config.max_redirects == 0 || (is_pull && !config.follow_pull_redirects) {
+        reqwest::redirect::Policy::none()
+    } else {
+        reqwest::redirect::Policy::limited(config.max_redirects)
+    };
+    let mut headers = reqwest::header::HeaderMap::new();
+    for (name, value) in &config.custom_headers {
+        // Checked when added in Settings, but the config file may have been edited by hand
+        match (
+            reqwest::header::HeaderName::from_bytes(name.as_bytes()),
+            reqwest::header::HeaderValue::from_str(value),
+        ) {
+            (Ok(name), Ok(mut value)) => {
+                value.set_sensitive(true); // Keep API keys out of debug output
+                headers.insert(name, value);
+            }
+            _ => warn!("Skipping the invalid custom header '{}'.", name),
+        }
+    }
+    reqwest::Client::builder()
+        .redirect(policy)
+        .default_headers(headers)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
+
+/// Turns a failed request into a readable message, calling out redirect loops explicitly.
+fn describe_request_error(url: &str, e: &reqwest::Error, config: &Config) -> String {
+    if e.is_redirect() {
+        format!(
+            "Too many redirects (limit {}) for {}. Check your proxy configuration for a redirect loop.",
+            config.max_redirects, url
+        )
+    } else {
+        format!("Network request failed for {}: {}", url, e)
+    }
+}
+
+/// Sends a request, retrying connection failures up to `config.max_retries` times. The delay starts at
+/// `config.base_backoff_ms` and doubles per retry. Timeouts and 502/503/504 answers are only retried
+/// for `idempotent` (read-only) requests: the server may have acted on the first attempt already, and
+/// repeating e.g. a create or delete would run it twice or fail with "not found".
+async fn send_with_retry(
+    request: reqwest::RequestBuilder,
+    url: &str,
+    config: &Config,
+    idempotent: bool,
+) -> Result<reqwest::Response, String> {
+    let mut retries_done = 0;
+    loop {
+        // Bodies used here are plain JSON, so cloning only fails for streamed bodies
+        let Some(attempt) = request.try_clone() else {
+            return request.send().await.map_err(|e| describe_request_error(url, &e, config));
+        };
+        let result = attempt.send().await;
+        let retry_reason = match &result {
+            // Nothing reached the server yet
+            Err(e) if e.is_connect() => Some(e.to_string()),
+            Ok(res) if idempotent && matches!(res.status().as_u16(), 502..=504) => Some(res.status().to_string()),
+            Err(e) if idempotent && e.is_timeout() => Some(e.to_string()),
+            _ => None,
+        };
+        match retry_reason {
+            Some(reason) if retries_done < config.max_retries => {
+                let delay_ms = config.base_backoff_ms.saturating_mul(1 << retries_done.min(16));
+                retries_done += 1;
+                warn!(
+                    "Request to {} failed ({}), retrying in {} ms ({}/{}).",
+                    url, reason, delay_ms, retries_done, config.max_retries
+                );
+                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            }
+            _ => return result.map_err(|e| describe_request_error(url, &e, config)),
+        }
+    }
+}
+
+/// Describes a redirect response that was not followed (redirects disabled for this request).
+fn redirect_status_error(res: &reqwest::Response, url: &str) -> String {
+    let location = res
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("an unknown location");
+    format!(
+        "{} redirected ({}) to {}, but following redirects is disabled for this request. \
+         Point the Ollama host at the final address, or allow redirects in Settings.",
+        url,
+        res.status(),
+        location
+    )
+}
+
 // --- Async Operations ---
 
+/// Reasons `pull_model_async` stops early without the pull having failed. Returned boxed like any
+/// other error; callers tell them apart with `downcast_ref::<PullInterrupted>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PullInterrupted {
+    /// The receiving end of the update channel is gone (e.g. the window closed while a pull was
+    /// running), so the task stops instead of working for nobody.
+    ChannelClosed,
+    /// The pull's cancel flag was set; the model is skipped, not failed.
+    Cancelled,
+}
+
+impl std::fmt::Display for PullInterrupted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PullInterrupted::ChannelClosed => write!(f, "Update channel closed, the application is shutting down"),
+            PullInterrupted::Cancelled => write!(f, "Pull cancelled by the user"),
+        }
+    }
+}
+
+impl std::error::Error for PullInterrupted {}
+
+/// How often a pull checks its cancel flag while waiting for the server.
+const CANCEL_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+/// How often a streamed pull re-checks the free space of the model store (see `Config::disk_guard`).
+const DISK_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Returns an error message if the model store's volume has less than `needed` bytes free on top
+/// of the space to keep free. Nothing is checked without `Config::disk_guard` (check off, no local
+/// model store, or a remote Ollama host) or if the free space can't be determined.
+fn low_disk_error(config: &Config, model_id: &str, needed: u64) -> Option<String> {
+    let (dir, min_free) = config.disk_guard.as_ref()?;
+    let free = match fs2::available_space(dir) {
+        Ok(free) => free,
+        Err(e) => {
+            debug!("Could not determine free space for {}: {}", dir.display(), e);
+            return None;
+        }
+    };
+    if free >= needed.saturating_add(*min_free) {
+        return None;
+    }
+    Some(format!(
+        "Not enough disk space for {}: {} free in {}, {} needed plus {} to keep free (see Settings → Downloads → Disk Space)",
+        model_id,
+        format_size(free),
+        dir.display(),
+        format_size(needed),
+        format_size(*min_free)
+    ))
+}
+
+// Resolves once `cancel` is set, for racing a request against a cancellation.
+async fn wait_for_cancel(cancel: &AtomicBool) {
+    while !cancel.load(Ordering::Relaxed) {
+        tokio::time::sleep(CANCEL_CHECK_INTERVAL).await;
+    }
+}
+
 /// Asynchronously pulls a model from the Ollama server using the `/api/pull` endpoint.
-/// Streams progress updates back to the UI thread via the sender.
+/// Streams progress updates back to the UI thread via the sender. Setting `cancel` stops the pull
+/// (closing the connection, which also stops it on the server) with `PullInterrupted::Cancelled`.
 pub async fn pull_model_async(
     model_id: &str,
     config: &Config,
     sender: Sender<UpdateMessage>,
+    cancel: Arc<AtomicBool>,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let client = reqwest::Client::new();
-    // Ensure host URL starts with http:// or https://
-    let host = if config.ollama_host.starts_with("http://") || config.ollama_host.starts_with("https://")
-    {
-        config.ollama_host.clone()
-    } else {
-        format!("http://{}", config.ollama_host) // Prepend http:// if missing
-    };
+    let client = build_client(config, true)?;
+    let host = base_url(config);
     let url = format!("{}/api/pull", host);
-    let request_body = serde_json::json!({ "name": model_id, "stream": true });
+    let request_body = serde_json::json!({ "name": model_id, "stream": config.stream_pulls });
+
+    // Refuse to start when the model (if its size is known) wouldn't fit, instead of failing halfway
+    if let Some(err_msg) = low_disk_error(config, model_id, known_model_size(model_id).unwrap_or(0)) {
+        error!("{}", err_msg);
+        let _ = sender.send(UpdateMessage::Log(format!("ERROR: {}", err_msg)));
+        return Err(err_msg.into());
+    }
 
     debug!("Sending pull request to {} for model '{}'", url, model_id);
     // Send DEBUG log via channel as well, as logger might filter it
@@ -96,20 +492,23 @@ pub async fn pull_model_async(
     )));
 
     // Send the POST request
-    let res = client.post(&url).json(&request_body).send().await.map_err(|e| {
-        let err_msg = format!("Network request failed for {}: {}", url, e);
+    let response = tokio::select! {
+        response = send_with_retry(client.post(&url).json(&request_body), &url, config, false) => response,
+        _ = wait_for_cancel(&cancel) => return Err(PullInterrupted::Cancelled.into()),
+    };
+    let res = response.map_err(|err_msg| {
         error!("{}", err_msg); // Log error
         let _ = sender.send(UpdateMessage::Log(format!("ERROR: {}", err_msg))); // Send error to UI
         err_msg // Return error message
     })?;
 
     let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
     // Check if the request was successful (e.g., 2xx status code)
     if !status_code.is_success() {
-        let error_body = res
-            .text()
-            .await
-            .unwrap_or_else(|_| "Unknown server error".to_string()); // Read error body
+        let error_body = parse_error_body(&res.text().await.unwrap_or_default()); // Read error body
         error!(
             "Ollama server at {} returned error status {}: {}",
             host, status_code, error_body
@@ -123,14 +522,67 @@ pub async fn pull_model_async(
         return Err(format!("Server error ({}) from {}: {}", status_code, host, error_body).into());
     }
 
+    // Non-streaming mode: the server answers once, with the final status, when the pull is done
+    if !config.stream_pulls {
+        let body = tokio::select! {
+            body = res.text() => body,
+            _ = wait_for_cancel(&cancel) => return Err(PullInterrupted::Cancelled.into()),
+        }
+        .map_err(|e| format!("Failed to read pull response for {}: {}", model_id, e))?;
+        trace!("Non-streaming pull response for {}: {}", model_id, body);
+        if let Some(err_msg) = find_error_in_body(&body) {
+            error!("Pull error reported for {}: {}", model_id, err_msg);
+            let _ = sender.send(UpdateMessage::Log(format!("ERROR: Pull error: {}", err_msg)));
+            return Err(format!("Pull failed for {}: {}", model_id, err_msg).into());
+        }
+        let final_status = serde_json::from_str::<OllamaPullStatus>(body.trim())
+            .map(|status| status.status)
+            .unwrap_or_else(|_| body.trim().to_string());
+        debug!("[{}] Final status: {}", model_id, final_status);
+        let _ = sender.send(UpdateMessage::Log(format!("INFO: [{}] {}", model_id, final_status)));
+        let _ = sender.send(UpdateMessage::StatusText(final_status));
+        let _ = sender.send(UpdateMessage::Progress(1.0));
+        return Ok(());
+    }
+
     // Process the response stream
     let mut stream = res.bytes_stream();
     let mut last_digest = String::new(); // Track the current layer digest
     let mut current_total: Option<u64> = None; // Total size of the current layer
     let mut layer_completed: Option<u64> = None; // Completed bytes of the current layer
+    let mut last_status_text = String::new(); // Most progress lines repeat the same status
+    let mut receiver_closed = false; // Set when the UI stopped listening (see PullInterrupted::ChannelClosed)
+    let mut last_disk_check = Instant::now();
 
     // Iterate over chunks in the stream
-    while let Some(item) = stream.next().await {
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            // Dropping the stream closes the connection, which also stops the pull on the server
+            debug!("Pull of '{}' cancelled.", model_id);
+            return Err(PullInterrupted::Cancelled.into());
+        }
+        if receiver_closed {
+            // Dropping the stream closes the connection, which also stops the pull on the server
+            debug!("Update channel closed, stopping the pull of '{}'.", model_id);
+            return Err(PullInterrupted::ChannelClosed.into());
+        }
+        if last_disk_check.elapsed() >= DISK_CHECK_INTERVAL {
+            last_disk_check = Instant::now();
+            // Needs room for the rest of the current layer (Ollama doesn't announce later layers)
+            let layer_remaining = current_total.unwrap_or(0).saturating_sub(layer_completed.unwrap_or(0));
+            if let Some(err_msg) = low_disk_error(config, model_id, layer_remaining) {
+                // Dropping the stream closes the connection, which also stops the pull on the server
+                error!("Stopping the pull: {}", err_msg);
+                let _ = sender.send(UpdateMessage::Log(format!("ERROR: Stopping the pull: {}", err_msg)));
+                return Err(err_msg.into());
+            }
+        }
+        // Wake up regularly to check the cancel flag, e.g. while the server verifies a layer
+        let item = match tokio::time::timeout(CANCEL_CHECK_INTERVAL, stream.next()).await {
+            Ok(Some(item)) => item,
+            Ok(None) => break,
+            Err(_) => continue,
+        };
         let chunk = item.map_err(|e| format!("Stream error while pulling {}: {}", model_id, e))?;
         // Ollama streams JSON objects separated by newlines
         let lines = String::from_utf8_lossy(&chunk);
@@ -148,8 +600,11 @@ pub async fn pull_model_async(
                     let log_msg = format!("[{}] {}", model_id, status.status);
                     debug!("{}", log_msg); // Log status message at DEBUG
 
-                    // Send status text update to UI
-                    let _ = sender.send(UpdateMessage::StatusText(status.status.clone()));
+                    // Send status text update to UI, only when it changed (progress goes separately)
+                    if status.status != last_status_text {
+                        last_status_text = status.status.clone();
+                        receiver_closed |= sender.send(UpdateMessage::StatusText(status.status.clone())).is_err();
+                    }
 
                     // Send shorter status messages to UI log panel (INFO level)
                     if status.status.len() < 100 {
@@ -179,7 +634,7 @@ pub async fn pull_model_async(
                                 model_id, digest
                             )));
                             // Reset progress for the new layer
-                            let _ = sender.send(UpdateMessage::Progress(0.0));
+                            receiver_closed |= sender.send(UpdateMessage::Progress(0.0)).is_err();
                         } else {
                             // Update progress for the current layer
                             layer_completed = status.completed;
@@ -202,7 +657,7 @@ pub async fn pull_model_async(
                         } else {
                             0.0
                         };
-                        let _ = sender.send(UpdateMessage::Progress(progress));
+                        receiver_closed |= sender.send(UpdateMessage::Progress(progress)).is_err();
                     }
 
                     // Calculate and send layer progress if possible
@@ -217,7 +672,7 @@ pub async fn pull_model_async(
                                 progress
                             );
                             // Send progress, ensuring it doesn't exceed 1.0
-                            let _ = sender.send(UpdateMessage::Progress(progress.min(1.0)));
+                            receiver_closed |= sender.send(UpdateMessage::Progress(progress.min(1.0))).is_err();
                         } else {
                             // Handle cases where total is 0 (e.g., layer already exists)
                             let progress = if status.status.contains("pulling")
@@ -227,12 +682,12 @@ pub async fn pull_model_async(
                             } else {
                                 1.0 // Assume complete if not pulling/downloading and total is 0
                             };
-                            let _ = sender.send(UpdateMessage::Progress(progress));
+                            receiver_closed |= sender.send(UpdateMessage::Progress(progress)).is_err();
                         }
                     } else if status.status.contains("success") {
                         // If no layer info but status is success, report 100% progress
                         trace!("[{}] Step success, progress 1.0", model_id);
-                        let _ = sender.send(UpdateMessage::Progress(1.0));
+                        receiver_closed |= sender.send(UpdateMessage::Progress(1.0)).is_err();
                     }
                 }
                 Err(e) => {
@@ -263,14 +718,8 @@ pub async fn list_models_async(
     config: &Config,
     sender: Sender<UpdateMessage>,
 ) -> Result<Vec<OllamaModel>, Box<dyn std::error::Error + Send + Sync>> {
-    let client = reqwest::Client::new();
-    // Ensure host URL starts with http:// or https://
-    let host = if config.ollama_host.starts_with("http://") || config.ollama_host.starts_with("https://")
-    {
-        config.ollama_host.clone()
-    } else {
-        format!("http://{}", config.ollama_host) // Prepend http:// if missing
-    };
+    let client = build_client(config, false)?;
+    let host = base_url(config);
     let url = format!("{}/api/tags", host);
     debug!("Sending list request to {}", url);
     let _ = sender.send(UpdateMessage::Log(format!(
@@ -278,20 +727,20 @@ pub async fn list_models_async(
         url
     )));
 
-    // Send the GET request
-    let res = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Network request failed for {}: {}", url, e))?;
+    // Send the GET request; only an unreachable server (not an error response) may use the CLI instead
+    let res = match send_with_retry(client.get(&url), &url, config, true).await {
+        Ok(res) => res,
+        Err(e) if config.cli_list_fallback => return list_models_via_cli(&sender, e).await,
+        Err(e) => return Err(e.into()),
+    };
 
     let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
     // Check for non-success status codes
     if !status_code.is_success() {
-        let error_body = res
-            .text()
-            .await
-            .unwrap_or_else(|_| "Unknown server error".to_string());
+        let error_body = parse_error_body(&res.text().await.unwrap_or_default());
         error!(
             "Ollama server at {} returned error status {}: {}",
             host, status_code, error_body
@@ -346,20 +795,514 @@ pub async fn list_models_async(
     Ok(response_body.models) // Return the processed list of models
 }
 
+/// How long `ollama list` may take before the fallback gives up.
+const CLI_LIST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Lists the models with the `ollama list` CLI after the API request failed with `api_error` (see
+/// `Config::cli_list_fallback`). The CLI finds the server its own way (its environment, default socket),
+/// which can work when the configured host doesn't (e.g. unusual socket setups). Fails with both errors if the CLI fails too.
+async fn list_models_via_cli(
+    sender: &Sender<UpdateMessage>,
+    api_error: String,
+) -> Result<Vec<OllamaModel>, Box<dyn std::error::Error + Send + Sync>> {
+    warn!("Listing models via the API failed ({}), trying `ollama list`.", api_error);
+    let _ = sender.send(UpdateMessage::Log(format!(
+        "WARN: The Ollama API is unreachable ({}), trying the `ollama list` CLI instead.",
+        api_error
+    )));
+
+    // On timeout the output future is dropped, which kills the process instead of leaving it running
+    let command = tokio::process::Command::new("ollama").arg("list").kill_on_drop(true).output();
+    let cli_result = match tokio::time::timeout(CLI_LIST_TIMEOUT, command).await {
+        Err(_) => Err(format!("`ollama list` did not finish within {} s", CLI_LIST_TIMEOUT.as_secs())),
+        Ok(Err(e)) => Err(format!("`ollama list` could not be run (is the Ollama CLI installed?): {}", e)),
+        Ok(Ok(output)) if !output.status.success() => Err(format!(
+            "`ollama list` failed ({}): {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )),
+        Ok(Ok(output)) => parse_cli_model_list(&String::from_utf8_lossy(&output.stdout)),
+    };
+
+    match cli_result {
+        Ok(models) => {
+            warn!("Listed {} models via `ollama list` (the API is unreachable).", models.len());
+            let _ = sender.send(UpdateMessage::Log(format!(
+                "WARN: Listed {} models via `ollama list`. Digests are shortened, sizes rounded and modification times relative.",
+                models.len()
+            )));
+            Ok(models)
+        }
+        Err(cli_error) => {
+            error!("CLI fallback failed: {}", cli_error);
+            let _ = sender.send(UpdateMessage::Log(format!("ERROR: CLI fallback failed: {}", cli_error)));
+            Err(format!("{} (CLI fallback: {})", api_error, cli_error).into())
+        }
+    }
+}
+
+/// Parses the table printed by `ollama list` (columns NAME, ID, SIZE, MODIFIED, separated by tabs or
+/// aligned with spaces). The ID is the first 12 characters of the digest, the size is rounded (e.g. `4.7 GB`) and the
+/// modification time is relative (e.g. `3 weeks ago`), so it is kept as text and sorts last by Modified.
+fn parse_cli_model_list(output: &str) -> Result<Vec<OllamaModel>, String> {
+    let split_tabs = |line: &str| -> Vec<String> { line.split('\t').map(|cell| cell.trim().to_string()).collect() };
+    let mut lines = output.lines().filter(|line| !line.trim().is_empty());
+    let header_line = lines.next().unwrap_or_default();
+    let tab_separated = header_line.contains('\t');
+    let header: Vec<String> = if tab_separated {
+        split_tabs(header_line)
+    } else {
+        header_line.split_whitespace().map(str::to_string).collect()
+    };
+    // Without tabs the columns are padded with spaces, so rows are cut where the header's columns start
+    // (in chars); splitting on whitespace would shift every cell after an empty one
+    let header_chars: Vec<char> = header_line.chars().collect();
+    let column_starts: Vec<usize> = (0..header_chars.len())
+        .filter(|&index| {
+            !header_chars[index].is_whitespace() && (index == 0 || header_chars[index - 1].is_whitespace())
+        })
+        .collect();
+    let split_row = |line: &str| -> Vec<String> {
+        if tab_separated {
+            return split_tabs(line);
+        }
+        let chars: Vec<char> = line.chars().collect();
+        column_starts
+            .iter()
+            .enumerate()
+            .map(|(index, &start)| {
+                let end = column_starts.get(index + 1).map_or(chars.len(), |&next| next.min(chars.len()));
+                chars[start.min(end)..end].iter().collect::<String>().trim().to_string()
+            })
+            .collect()
+    };
+    let column = |name: &str| header.iter().position(|cell| cell.eq_ignore_ascii_case(name));
+    let (Some(name_col), Some(id_col), Some(size_col), Some(modified_col)) =
+        (column("NAME"), column("ID"), column("SIZE"), column("MODIFIED"))
+    else {
+        return Err(format!("unexpected `ollama list` output (header: {:?})", header));
+    };
+
+    let mut models = Vec::new();
+    for line in lines {
+        let cells = split_row(line);
+        let cell = |index: usize| cells.get(index).cloned().unwrap_or_default();
+        let name = cell(name_col);
+        if name.is_empty() {
+            continue;
+        }
+        let size = parse_cli_size(&cell(size_col)).unwrap_or_else(|| {
+            warn!("Could not parse the size '{}' of '{}' from `ollama list`.", cell(size_col), name);
+            0
+        });
+        let modified_at = cell(modified_col);
+        models.push(OllamaModel {
+            name,
+            size,
+            digest: cell(id_col),
+            size_human: format_size(size),
+            modified_local: Some(modified_at.clone()),
+            modified_at,
+            listed_via_cli: true,
+            ..Default::default()
+        });
+    }
+    debug!("Parsed {} models from `ollama list` output.", models.len());
+    Ok(models)
+}
+
+// Parses a size as printed by the Ollama CLI (decimal units: `4.7 GB`, `350 MB`, `12 B`).
+fn parse_cli_size(text: &str) -> Option<u64> {
+    let (number, unit) = text.trim().split_once(' ')?;
+    let number: f64 = number.parse().ok()?;
+    let multiplier = match unit.trim().to_uppercase().as_str() {
+        "B" => 1.0,
+        "KB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        _ => return None,
+    };
+    Some((number * multiplier).round() as u64)
+}
+
+/// Asynchronously fetches the models currently loaded into memory using `/api/ps`.
+/// Used for the app-maintained "last used" tracking, so failures are only logged at DEBUG.
+pub async fn list_running_models_async(
+    config: &Config,
+) -> Result<Vec<OllamaRunningModel>, Box<dyn std::error::Error + Send + Sync>> {
+    let client = build_client(config, false)?;
+    let host = base_url(config);
+    let url = format!("{}/api/ps", host);
+    trace!("Sending running models request to {}", url);
+
+    let res = send_with_retry(client.get(&url), &url, config, true).await?;
+
+    let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
+    if !status_code.is_success() {
+        let error_body = parse_error_body(&res.text().await.unwrap_or_default());
+        return Err(format!("Server error ({}) from {}: {}", status_code, host, error_body).into());
+    }
+
+    let response_body: OllamaPsResponse = res
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON response from {}: {}", url, e))?;
+    Ok(response_body.models)
+}
+
+/// Asynchronously fetches the server version using the `/api/version` endpoint.
+pub async fn get_server_version_async(
+    config: &Config,
+) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    let client = build_client(config, false)?;
+    let host = base_url(config);
+    let url = format!("{}/api/version", host);
+    trace!("Sending version request to {}", url);
+
+    let res = send_with_retry(client.get(&url), &url, config, true).await?;
+
+    let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
+    if !status_code.is_success() {
+        let error_body = parse_error_body(&res.text().await.unwrap_or_default());
+        return Err(format!("Server error ({}) from {}: {}", status_code, host, error_body).into());
+    }
+
+    let response_body: OllamaVersionResponse = res
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON response from {}: {}", url, e))?;
+    Ok(response_body.version)
+}
+
+/// Runs the connection diagnostics against the configured host, one step after the other, sending each
+/// result as `UpdateMessage::DiagnosticStep` as soon as it is known. Requests are sent once (no retries),
+/// so the timings are real. Stops after the first failing step, as the later ones can't succeed then.
+pub async fn run_diagnostics_async(config: &Config, run_id: u64, sender: Sender<UpdateMessage>) {
+    let send_step = |index: usize, started: Instant, result: Result<String, String>| {
+        let failed = result.is_err();
+        let _ = sender.send(UpdateMessage::DiagnosticStep(
+            run_id,
+            DiagnosticStep { index, result, elapsed_ms: started.elapsed().as_millis() },
+        ));
+        failed
+    };
+
+    // 1. Resolve URL: how the host setting is turned into the URLs the app requests
+    let started = Instant::now();
+    let host = base_url(config);
+    let shown_host = redact_url_credentials(&host); // The details are shown and can be copied
+    let parsed = reqwest::Url::parse(&host)
+        .map_err(|e| format!("'{}' is not a valid URL: {}", shown_host, e))
+        .and_then(|url| match (url.host_str(), url.port_or_known_default()) {
+            (Some(name), Some(port)) => Ok((name.trim_matches(['[', ']']).to_string(), port)),
+            _ => Err(format!("'{}' has no host name or port", shown_host)),
+        });
+    let (host_name, port) = match parsed {
+        Ok(target) => target,
+        Err(e) => {
+            send_step(0, started, Err(e));
+            return;
+        }
+    };
+    let headers_note = if config.custom_headers.is_empty() {
+        String::new()
+    } else {
+        format!(" ({} custom header(s))", config.custom_headers.len())
+    };
+    send_step(0, started, Ok(format!("{} → {}:{}{}", shown_host, host_name, port, headers_note)));
+
+    // 2. DNS lookup
+    let started = Instant::now();
+    let addresses: Vec<std::net::SocketAddr> =
+        match tokio::time::timeout(Duration::from_secs(5), tokio::net::lookup_host((host_name.as_str(), port))).await {
+            Ok(Ok(addresses)) => addresses.collect(),
+            Ok(Err(e)) => {
+                send_step(1, started, Err(format!("Cannot resolve '{}': {}", host_name, e)));
+                return;
+            }
+            Err(_) => {
+                send_step(1, started, Err(format!("Resolving '{}' timed out after 5 s", host_name)));
+                return;
+            }
+        };
+    let Some(address) = addresses.first().copied() else {
+        send_step(1, started, Err(format!("'{}' resolved to no addresses", host_name)));
+        return;
+    };
+    let address_list: Vec<String> = addresses.iter().map(|a| a.ip().to_string()).collect();
+    send_step(1, started, Ok(address_list.join(", ")));
+
+    // 3. TCP connect (firewall, wrong port, server not running)
+    let started = Instant::now();
+    let connect_result = match tokio::time::timeout(Duration::from_secs(5), tokio::net::TcpStream::connect(address)).await {
+        Ok(Ok(_)) => Ok(format!("Connected to {}", address)),
+        Ok(Err(e)) => Err(format!("Cannot connect to {}: {} (is Ollama running and listening there? firewall?)", address, e)),
+        Err(_) => Err(format!("Connecting to {} timed out after 5 s (firewall dropping packets?)", address)),
+    };
+    if send_step(2, started, connect_result) {
+        return;
+    }
+
+    let client = match build_client(config, false) {
+        Ok(client) => client,
+        Err(e) => {
+            send_step(3, Instant::now(), Err(e));
+            return;
+        }
+    };
+
+    // 4./5. The API endpoints the app depends on (proxies, auth, wrong service on that port)
+    for (index, path) in [(3, "/api/version"), (4, "/api/tags")] {
+        let started = Instant::now();
+        let url = format!("{}{}", host, path);
+        let shown_url = format!("{}{}", shown_host, path);
+        let result = match client.get(&url).timeout(Duration::from_secs(10)).send().await {
+            Ok(res) => {
+                let status_code = res.status();
+                let body = res.text().await.unwrap_or_default();
+                if status_code.is_success() {
+                    Ok(describe_diagnostic_body(path, status_code, &body))
+                } else if status_code.is_redirection() {
+                    Err(format!("{} answered with a redirect ({}), check the host URL or proxy", shown_url, status_code))
+                } else if status_code == reqwest::StatusCode::UNAUTHORIZED || status_code == reqwest::StatusCode::FORBIDDEN {
+                    Err(format!("{} ({}): {} (a proxy may need credentials, see Custom Headers)", shown_url, status_code, parse_error_body(&body)))
+                } else {
+                    Err(format!("{} ({}): {}", shown_url, status_code, parse_error_body(&body)))
+                }
+            }
+            Err(e) => Err(redact_url_credentials(&describe_request_error(&shown_url, &e, config))),
+        };
+        if send_step(index, started, result) {
+            return;
+        }
+    }
+}
+
+// Summarizes a successful diagnostic response: the version, the number of models, or (if it isn't
+// what Ollama sends) a hint that something else answers on that port.
+fn describe_diagnostic_body(path: &str, status_code: reqwest::StatusCode, body: &str) -> String {
+    let parsed = if path == "/api/version" {
+        serde_json::from_str::<OllamaVersionResponse>(body).ok().map(|v| format!("Ollama {}", v.version))
+    } else {
+        serde_json::from_str::<OllamaTagsResponse>(body).ok().map(|tags| format!("{} model(s) listed", tags.models.len()))
+    };
+    parsed.unwrap_or_else(|| format!("{}, but not an Ollama response (another service on this port?)", status_code))
+}
+
+/// Asynchronously loads a model into memory by sending `/api/generate` an empty prompt.
+/// The model then stays loaded for `keep_alive` (e.g. "10m") and shows up in `/api/ps`.
+pub async fn warm_model_async(
+    model_name: &str,
+    keep_alive: &str,
+    config: &Config,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let client = build_client(config, false)?;
+    let host = base_url(config);
+    let url = format!("{}/api/generate", host);
+    let request_body = OllamaWarmRequest {
+        model: model_name.to_string(),
+        prompt: String::new(), // An empty prompt only loads the model
+        keep_alive: keep_alive.to_string(),
+        stream: false,
+    };
+    debug!("Sending warm-up request to {} for model '{}'", url, model_name);
+
+    let res = send_with_retry(client.post(&url).json(&request_body), &url, config, false).await?;
+
+    let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
+    let body = res.text().await.unwrap_or_default();
+    trace!("Warm-up response body for '{}': {}", model_name, body);
+    if !status_code.is_success() {
+        return Err(format!(
+            "Server error ({}) from {}: {}",
+            status_code,
+            host,
+            parse_error_body(&body)
+        )
+        .into());
+    }
+    if let Some(err_msg) = find_error_in_body(&body) {
+        return Err(format!("Ollama reported an error: {}", err_msg).into());
+    }
+    Ok(())
+}
+
+/// Asynchronously generates a (non-streamed) completion via `/api/generate`, returning the response text.
+/// Used by the prompt workbench to try a system prompt and parameters before creating a model.
+pub async fn generate_async(
+    request_body: &OllamaGenerateRequest,
+    config: &Config,
+) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    let client = build_client(config, false)?;
+    let host = base_url(config);
+    let url = format!("{}/api/generate", host);
+    debug!("Sending generate request to {} for model '{}'", url, request_body.model);
+
+    let res = send_with_retry(client.post(&url).json(request_body), &url, config, false).await?;
+
+    let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
+    let body = res.text().await.unwrap_or_default();
+    trace!("Generate response body for '{}': {}", request_body.model, body);
+    if !status_code.is_success() {
+        return Err(format!("Server error ({}) from {}: {}", status_code, host, parse_error_body(&body)).into());
+    }
+    if let Some(err_msg) = find_error_in_body(&body) {
+        return Err(format!("Ollama reported an error: {}", err_msg).into());
+    }
+    let response: OllamaGenerateResponse = serde_json::from_str(&body)
+        .map_err(|e| format!("Failed to parse generate response from {}: {}", url, e))?;
+    Ok(response.response)
+}
+
+/// Asynchronously generates a streamed completion via `/api/generate`, sending each piece of the response
+/// to the UI as `UpdateMessage::WorkbenchToken`. Checks `cancel` between chunks (and while waiting for
+/// one); dropping the stream closes the connection, which makes Ollama stop generating.
+pub async fn generate_stream_async(
+    request_body: &OllamaGenerateRequest,
+    config: &Config,
+    sender: Sender<UpdateMessage>,
+    cancel: Arc<AtomicBool>,
+) -> Result<GenerateEnd, Box<dyn std::error::Error + Send + Sync>> {
+    let client = build_client(config, false)?;
+    let host = base_url(config);
+    let url = format!("{}/api/generate", host);
+    debug!("Sending streamed generate request to {} for model '{}'", url, request_body.model);
+
+    let res = send_with_retry(client.post(&url).json(request_body), &url, config, false).await?;
+
+    let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
+    if !status_code.is_success() {
+        let error_body = parse_error_body(&res.text().await.unwrap_or_default());
+        return Err(format!("Server error ({}) from {}: {}", status_code, host, error_body).into());
+    }
+
+    // Ollama streams JSON objects separated by newlines; a chunk may end mid-line
+    let mut stream = res.bytes_stream();
+    let mut pending = String::new();
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            debug!("Generation with '{}' cancelled.", request_body.model);
+            return Ok(GenerateEnd::Cancelled);
+        }
+        // Wake up regularly to check the cancel flag, e.g. while the model is still loading
+        let item = match tokio::time::timeout(Duration::from_millis(200), stream.next()).await {
+            Ok(Some(item)) => item,
+            Ok(None) => break,
+            Err(_) => continue,
+        };
+        let chunk = item.map_err(|e| format!("Stream error while generating with {}: {}", request_body.model, e))?;
+        pending.push_str(&String::from_utf8_lossy(&chunk));
+        while let Some(newline) = pending.find('\n') {
+            let line: String = pending.drain(..=newline).collect();
+            if line.trim().is_empty() {
+                continue;
+            }
+            trace!("Generate line from {}: {}", request_body.model, line.trim());
+            let response: OllamaGenerateResponse = serde_json::from_str(line.trim())
+                .map_err(|e| format!("Failed to parse generate response from {}: {}", url, e))?;
+            if let Some(err_msg) = response.error {
+                return Err(format!("Ollama reported an error: {}", err_msg).into());
+            }
+            if !response.response.is_empty()
+                && sender.send(UpdateMessage::WorkbenchToken(response.response.clone())).is_err()
+            {
+                debug!("Update channel closed, stopping the generation with '{}'.", request_body.model);
+                return Ok(GenerateEnd::Cancelled);
+            }
+            if response.done {
+                return Ok(GenerateEnd::Done(response.tokens_per_sec()));
+            }
+        }
+    }
+    Err(format!("The generate stream from {} ended before the response was done", url).into())
+}
+
+/// Asynchronously creates a model via `/api/create` from a base model, a system prompt and parameters.
+/// Uses the JSON form of the endpoint (`from`, `system`, `parameters`), which needs Ollama 0.5.5 or newer.
+pub async fn create_model_async(
+    request_body: &OllamaCreateRequest,
+    config: &Config,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let client = build_client(config, false)?;
+    let host = base_url(config);
+    let url = format!("{}/api/create", host);
+    debug!("Sending create request to {} for model '{}' (from '{}')", url, request_body.model, request_body.from);
+
+    let res = send_with_retry(client.post(&url).json(request_body), &url, config, false).await?;
+
+    let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
+    let body = res.text().await.unwrap_or_default();
+    trace!("Create response body for '{}': {}", request_body.model, body);
+    if !status_code.is_success() {
+        return Err(format!("Server error ({}) from {}: {}", status_code, host, parse_error_body(&body)).into());
+    }
+    if let Some(err_msg) = find_error_in_body(&body) {
+        return Err(format!("Ollama reported an error: {}", err_msg).into());
+    }
+    Ok(())
+}
+
+/// Asynchronously fetches the details of a single model using the `/api/show` endpoint.
+pub async fn show_model_async(
+    model_name: &str,
+    config: &Config,
+) -> Result<OllamaShowResponse, Box<dyn std::error::Error + Send + Sync>> {
+    let client = build_client(config, false)?;
+    let host = base_url(config);
+    let url = format!("{}/api/show", host);
+    let request_body = serde_json::json!({ "name": model_name });
+    debug!("Sending show request to {} for model '{}'", url, model_name);
+
+    // A POST, but /api/show only reads, so it is as safe to repeat as a GET
+    let res = send_with_retry(client.post(&url).json(&request_body), &url, config, true).await?;
+
+    let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
+    if !status_code.is_success() {
+        let error_body = parse_error_body(&res.text().await.unwrap_or_default());
+        warn!(
+            "Ollama server at {} returned error status {} for show '{}': {}",
+            host, status_code, model_name, error_body
+        );
+        return Err(format!("Server error ({}) from {}: {}", status_code, host, error_body).into());
+    }
+
+    let response_body: OllamaShowResponse = res
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse JSON response from {}: {}", url, e))?;
+    Ok(response_body)
+}
+
 /// Asynchronously deletes a model from the Ollama server using the `/api/delete` endpoint.
 pub async fn delete_model_async(
     model_name: &str,
     config: &Config,
     sender: Sender<UpdateMessage>,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let client = reqwest::Client::new();
-    // Ensure host URL starts with http:// or https://
-    let host = if config.ollama_host.starts_with("http://") || config.ollama_host.starts_with("https://")
-    {
-        config.ollama_host.clone()
-    } else {
-        format!("http://{}", config.ollama_host) // Prepend http:// if missing
-    };
+    let client = build_client(config, false)?;
+    let host = base_url(config);
     let url = format!("{}/api/delete", host);
     // Create the request body required by the delete API
     let request_body = OllamaDeleteRequest {
@@ -376,17 +1319,29 @@ pub async fn delete_model_async(
     )));
 
     // Send the DELETE request with the JSON body
-    let res = client
-        .delete(&url)
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("Network request failed for {}: {}", url, e))?;
+    let res = send_with_retry(client.delete(&url).json(&request_body), &url, config, false).await?;
 
     let status_code = res.status();
+    if status_code.is_redirection() {
+        return Err(redirect_status_error(&res, &url).into());
+    }
     // Handle different response statuses
     if status_code.is_success() {
-        // Success (e.g., 200 OK)
+        // Success (e.g., 200 OK). Some server versions stream a body back; read it fully
+        // and make sure it doesn't carry an error despite the status code.
+        let body = res.text().await.unwrap_or_default();
+        trace!("Delete response body for '{}': {}", model_name, body);
+        if let Some(err_msg) = find_error_in_body(&body) {
+            error!(
+                "Ollama server at {} reported an error deleting model '{}': {}",
+                host, model_name, err_msg
+            );
+            let _ = sender.send(UpdateMessage::Log(format!(
+                "ERROR deleting model '{}': {}",
+                model_name, err_msg
+            )));
+            return Err(format!("Server error deleting {}: {}", model_name, err_msg).into());
+        }
         debug!(
             "Successfully received response for deleting model '{}'.",
             model_name
@@ -405,10 +1360,7 @@ pub async fn delete_model_async(
         Ok(()) // Still return Ok, as the desired state (model not present) is achieved
     } else {
         // Other errors
-        let error_body = res
-            .text()
-            .await
-            .unwrap_or_else(|_| "Unknown server error".to_string());
+        let error_body = parse_error_body(&res.text().await.unwrap_or_default());
         error!(
             "Ollama server at {} returned error status {} deleting model '{}': {}",
             host, status_code, model_name, error_body
@@ -425,3 +1377,142 @@ pub async fn delete_model_async(
         .into()) // Return the error
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // As printed by `ollama list` (columns padded with spaces, trailing padding included)
+    const CLI_LIST_OUTPUT: &str = "\
+NAME                       ID              SIZE      MODIFIED     \n\
+llama3:latest              365c0bd3c000    4.7 GB    2 weeks ago     \n\
+nomic-embed-text:latest    0a109f422b47    274 MB    3 months ago    \n\
+custom/blob:latest         1b2c3d4e5f60              5 minutes ago    \n";
+
+    #[test]
+    fn parses_padded_cli_list_output() {
+        let models = parse_cli_model_list(CLI_LIST_OUTPUT).unwrap();
+        let rows: Vec<(&str, &str, u64, &str)> = models
+            .iter()
+            .map(|m| (m.name.as_str(), m.digest.as_str(), m.size, m.modified_at.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("llama3:latest", "365c0bd3c000", 4_700_000_000, "2 weeks ago"),
+                ("nomic-embed-text:latest", "0a109f422b47", 274_000_000, "3 months ago"),
+                // An empty SIZE cell doesn't shift MODIFIED into it
+                ("custom/blob:latest", "1b2c3d4e5f60", 0, "5 minutes ago"),
+            ]
+        );
+        assert!(models.iter().all(|m| m.listed_via_cli && m.modified_dt.is_none()));
+    }
+
+    #[test]
+    fn parses_tab_separated_cli_list_output() {
+        let output = "NAME\tID\tSIZE\tMODIFIED\nllama3:latest\t365c0bd3c000\t\t2 weeks ago\n";
+        let models = parse_cli_model_list(output).unwrap();
+        assert_eq!((models[0].size, models[0].modified_at.as_str()), (0, "2 weeks ago"));
+    }
+
+    #[test]
+    fn unknown_cli_list_header_is_an_error() {
+        assert!(parse_cli_model_list("MODEL  DIGEST  BYTES\nllama3  365c0bd3c000  4.7 GB\n").is_err());
+        assert!(parse_cli_model_list("").is_err());
+    }
+
+    #[test]
+    fn parses_rounded_cli_sizes() {
+        assert_eq!(parse_cli_size("4.7 GB"), Some(4_700_000_000));
+        assert_eq!(parse_cli_size("350 MB"), Some(350_000_000));
+        assert_eq!(parse_cli_size(" 12 kb "), Some(12_000));
+        assert_eq!(parse_cli_size(""), None);
+        assert_eq!(parse_cli_size("4.7GB"), None);
+        assert_eq!(parse_cli_size("1 PB"), None);
+    }
+
+    #[test]
+    fn base_url_adds_a_scheme_and_drops_trailing_slashes() {
+        let base_url_for = |ollama_host: &str| {
+            base_url(&Config {
+                ollama_host: ollama_host.to_string(),
+                host_generation: 0,
+                tz: chrono_tz::Tz::UTC,
+                stream_pulls: true,
+                max_redirects: 0,
+                follow_pull_redirects: false,
+                max_retries: 0,
+                base_backoff_ms: 0,
+                custom_headers: Vec::new(),
+                disk_guard: None,
+                cli_list_fallback: false,
+            })
+        };
+        assert_eq!(base_url_for("127.0.0.1:11434"), "http://127.0.0.1:11434");
+        assert_eq!(base_url_for("http://localhost:11434/"), "http://localhost:11434");
+        assert_eq!(base_url_for("https://ollama.example.com//"), "https://ollama.example.com");
+        assert_eq!(base_url_for("https://ollama.example.com/proxy/"), "https://ollama.example.com/proxy");
+    }
+
+    #[test]
+    fn pull_interruptions_are_told_apart_by_type_not_text() {
+        let closed: Box<dyn std::error::Error + Send + Sync> = PullInterrupted::ChannelClosed.into();
+        assert_eq!(closed.downcast_ref::<PullInterrupted>(), Some(&PullInterrupted::ChannelClosed));
+        // A server error that happens to carry the same message is still a failure
+        let lookalike: Box<dyn std::error::Error + Send + Sync> = closed.to_string().into();
+        assert_eq!(lookalike.downcast_ref::<PullInterrupted>(), None);
+        let cancelled: Box<dyn std::error::Error + Send + Sync> = PullInterrupted::Cancelled.into();
+        assert_eq!(cancelled.downcast_ref::<PullInterrupted>(), Some(&PullInterrupted::Cancelled));
+    }
+
+    // Parses a `/api/tags` payload with one model whose details end with `families_json`.
+    fn tags_with_families(families_json: &str) -> OllamaTagsResponse {
+        let body = format!(
+            r#"{{"models":[{{"name":"llama3:latest","modified_at":"2024-05-01T10:00:00Z","size":1,"digest":"abc",
+                "details":{{"format":"gguf","family":"llama"{}}}}}]}}"#,
+            families_json
+        );
+        serde_json::from_str(&body).expect("valid /api/tags payload")
+    }
+
+    #[test]
+    fn families_missing_null_or_empty_mean_no_families() {
+        for families_json in ["", r#","families":null"#, r#","families":[]"#] {
+            let tags = tags_with_families(families_json);
+            assert!(tags.models[0].details.families_list().is_empty(), "families: {:?}", families_json);
+        }
+        let tags = tags_with_families(r#","families":["llama","clip"]"#);
+        assert_eq!(tags.models[0].details.families_list(), ["llama", "clip"]);
+    }
+
+    #[test]
+    fn parse_error_body_reads_json_error() {
+        assert_eq!(
+            parse_error_body(r#"{"error":"model 'llama3:8b' not found"}"#),
+            "model 'llama3:8b' not found"
+        );
+    }
+
+    #[test]
+    fn parse_error_body_reads_streamed_error_line() {
+        let body = "{\"status\":\"pulling manifest\"}\n{\"error\":\"pull model manifest: file does not exist\"}\n";
+        assert_eq!(parse_error_body(body), "pull model manifest: file does not exist");
+    }
+
+    #[test]
+    fn find_error_in_body_ignores_success_bodies() {
+        // What delete_model_async checks a 200 response for
+        assert_eq!(find_error_in_body(""), None);
+        assert_eq!(find_error_in_body("{\"status\":\"success\"}\n"), None);
+        assert_eq!(
+            find_error_in_body("{\"status\":\"deleting\"}\n{\"error\":\"model is in use\"}"),
+            Some("model is in use".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_error_body_keeps_plain_text() {
+        assert_eq!(parse_error_body("  404 page not found\n"), "404 page not found");
+        assert_eq!(parse_error_body(" \n"), "Unknown server error");
+    }
+}
@@ -0,0 +1,299 @@
+// src/app/remote_api.rs
+// Optional local HTTP API for automation. Only listens on 127.0.0.1 and only forwards commands to the
+// UI thread (via UpdateMessage::RemoteCommand), so pulls started here use the same pipeline as the GUI.
+// The UI thread checks whether it is busy and starts the command in one step, and replies with the
+// outcome, which the API waits for before answering.
+//
+// Endpoints (JSON responses):
+//   GET  /status   Current status, status text and progress of the running operation.
+//   GET  /models   The models of the last model list refresh.
+//   POST /pull     Pulls the models in the body (one per line, or separated by commas/spaces).
+//   POST /refresh  Refreshes the model list.
+
+use crate::app::{
+    ollama::OllamaModel,
+    state::{AppStatus, UpdateMessage},
+    utils::validate_model_identifier,
+};
+use log::{debug, error, info, warn};
+use std::{
+    net::Ipv4Addr,
+    sync::{mpsc::Sender, Arc, Mutex},
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::oneshot,
+};
+
+/// Largest request body accepted (a list of model names is tiny).
+const MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// Longest request line or single header line accepted.
+const MAX_LINE_BYTES: usize = 8 * 1024;
+
+/// Largest total size of the headers accepted.
+const MAX_HEADER_BYTES: usize = 32 * 1024;
+
+/// Time a client gets to send its whole request, so idle connections don't hold a task forever.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time the UI thread gets to start (or refuse) a command before the API gives up waiting. The UI
+/// may not run while the window is minimized on some platforms.
+const COMMAND_REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A request read from a connection: method, path (without query) and body.
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+/// A command received through the remote API, executed on the UI thread.
+#[derive(Debug, Clone)]
+pub enum RemoteCommand {
+    /// Pull these models as one batch (already validated; the default namespace is applied by the app).
+    Pull(Vec<String>),
+    /// Refresh the model list.
+    Refresh,
+}
+
+/// The app state the API reads from, and the channel it sends commands through.
+pub struct RemoteApiState {
+    pub status: Arc<Mutex<AppStatus>>,
+    pub status_text: Arc<Mutex<String>>,
+    pub progress: Arc<Mutex<f32>>,
+    pub listed_models: Arc<Mutex<Vec<OllamaModel>>>,
+    pub sender: Sender<UpdateMessage>,
+    pub ctx: egui::Context, // Wakes up the UI, which may be idle, to handle commands
+}
+
+/// Listens on `127.0.0.1:<port>` until the task is aborted.
+pub async fn serve(port: u16, state: RemoteApiState) {
+    let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, port)).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("Failed to start the remote API on 127.0.0.1:{}: {}", port, e);
+            let _ = state.sender.send(UpdateMessage::Log(format!(
+                "ERROR: Failed to start the remote API on 127.0.0.1:{}: {}",
+                port, e
+            )));
+            return;
+        }
+    };
+    info!("Remote API listening on http://127.0.0.1:{}", port);
+    let state = Arc::new(state);
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let state = state.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream, &state).await {
+                        debug!("Remote API connection error: {}", e);
+                    }
+                });
+            }
+            Err(e) => warn!("Remote API failed to accept a connection: {}", e),
+        }
+    }
+}
+
+/// Reads one HTTP/1.1 request, answers it and closes the connection.
+async fn handle_connection(stream: TcpStream, state: &RemoteApiState) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+
+    let (code, body) = match tokio::time::timeout(READ_TIMEOUT, read_request(&mut reader)).await {
+        Err(_) => {
+            debug!("Remote API: no complete request within {} s, closing the connection.", READ_TIMEOUT.as_secs());
+            (408, serde_json::json!({ "error": "Request not received in time." }))
+        }
+        Ok(result) => match result? {
+            Ok(request) => {
+                let (code, body) = route(&request.method, &request.path, &request.body, state).await;
+                debug!("Remote API: {} {} -> {}", request.method, request.path, code);
+                (code, body)
+            }
+            Err((code, error)) => {
+                debug!("Remote API: rejected a request -> {}", code);
+                (code, serde_json::json!({ "error": error }))
+            }
+        },
+    };
+
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        code,
+        reason_phrase(code),
+        body.len(),
+        body
+    );
+    let mut stream = reader.into_inner();
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Reads the request line, headers and body of a request, or returns the status code and error
+/// message to answer with instead (oversized parts, requests from browsers).
+async fn read_request(reader: &mut BufReader<TcpStream>) -> std::io::Result<Result<Request, (u16, &'static str)>> {
+    let Some(request_line) = read_limited_line(reader, MAX_LINE_BYTES).await? else {
+        return Ok(Err((414, "Request line too long.")));
+    };
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default();
+    let path = path.split('?').next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut has_origin = false;
+    let mut host_is_local = false;
+    let mut header_bytes = 0;
+    loop {
+        let Some(line) = read_limited_line(reader, MAX_LINE_BYTES).await? else {
+            return Ok(Err((431, "Request header too long.")));
+        };
+        header_bytes += line.len();
+        if header_bytes > MAX_HEADER_BYTES {
+            return Ok(Err((431, "Request headers too large.")));
+        }
+        if line.trim().is_empty() {
+            break; // End of the headers (or of the stream)
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.parse().unwrap_or(0),
+                "origin" => has_origin = true,
+                "host" => {
+                    let hostname = value.rsplit_once(':').map_or(value, |(hostname, _)| hostname);
+                    host_is_local = hostname == "127.0.0.1" || hostname == "localhost";
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if has_origin || !host_is_local {
+        // Browsers send an Origin header (and web pages a foreign Host, via DNS rebinding):
+        // refuse those, so websites can't drive the API through the user's browser
+        return Ok(Err((403, "Requests from browsers are not accepted.")));
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Ok(Err((413, "Request body too large.")));
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).await?;
+    Ok(Ok(Request {
+        method,
+        path,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    }))
+}
+
+/// Reads one line of at most `max_bytes` (an empty string at the end of the stream), or None if the
+/// line is longer, without buffering more than that.
+async fn read_limited_line(reader: &mut BufReader<TcpStream>, max_bytes: usize) -> std::io::Result<Option<String>> {
+    let mut line = String::new();
+    (&mut *reader).take(max_bytes as u64).read_line(&mut line).await?;
+    if line.len() >= max_bytes && !line.ends_with('\n') {
+        return Ok(None);
+    }
+    Ok(Some(line))
+}
+
+/// Handles a request, returning the status code and JSON body.
+async fn route(method: &str, path: &str, body: &str, state: &RemoteApiState) -> (u16, serde_json::Value) {
+    match (method, path) {
+        ("GET", "/status") => {
+            let status = state.status.lock().unwrap().clone();
+            (
+                200,
+                serde_json::json!({
+                    "status": format!("{:?}", status),
+                    "status_text": state.status_text.lock().unwrap().clone(),
+                    "progress": *state.progress.lock().unwrap(),
+                }),
+            )
+        }
+        ("GET", "/models") => {
+            let models: Vec<serde_json::Value> = state
+                .listed_models
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|m| serde_json::json!({ "name": m.name, "size": m.size, "digest": m.digest }))
+                .collect();
+            (200, serde_json::json!(models))
+        }
+        ("POST", "/pull") => {
+            let models: Vec<String> = body
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect();
+            let invalid_models: Vec<String> =
+                models.iter().filter_map(|id| validate_model_identifier(id).err()).collect();
+            if models.is_empty() {
+                (400, serde_json::json!({ "error": "No models given." }))
+            } else if !invalid_models.is_empty() {
+                (400, serde_json::json!({ "error": format!("Invalid model identifier(s): {}", invalid_models.join("; ")) }))
+            } else {
+                info!("Remote API: pull requested for {} model(s).", models.len());
+                match run_command(state, RemoteCommand::Pull(models.clone())).await {
+                    Ok(()) => (202, serde_json::json!({ "started": models })),
+                    Err((code, error)) => (code, serde_json::json!({ "error": error })),
+                }
+            }
+        }
+        ("POST", "/refresh") => match run_command(state, RemoteCommand::Refresh).await {
+            Ok(()) => (202, serde_json::json!({ "started": "refresh" })),
+            Err((code, error)) => (code, serde_json::json!({ "error": error })),
+        },
+        (_, "/status" | "/models" | "/pull" | "/refresh") => {
+            (405, serde_json::json!({ "error": "Method not allowed." }))
+        }
+        _ => (404, serde_json::json!({ "error": "Not found." })),
+    }
+}
+
+/// Hands a command to the UI thread and waits until it was started, or refused because another
+/// operation is running (409). Returns the status code and error message to answer with otherwise.
+async fn run_command(state: &RemoteApiState, command: RemoteCommand) -> Result<(), (u16, String)> {
+    let (reply_sender, reply) = oneshot::channel();
+    if state.sender.send(UpdateMessage::RemoteCommand(command, reply_sender)).is_err() {
+        return Err((503, "The application is shutting down.".to_string()));
+    }
+    state.ctx.request_repaint(); // Wake up the UI, which may be idle
+    match tokio::time::timeout(COMMAND_REPLY_TIMEOUT, reply).await {
+        Ok(Ok(Ok(()))) => Ok(()),
+        Ok(Ok(Err(error))) => Err((409, error)),
+        Ok(Err(_)) => Err((503, "The application is shutting down.".to_string())),
+        Err(_) => Err((
+            504,
+            format!(
+                "The application didn't confirm the command within {} s (e.g. its window is minimized); it may still start.",
+                COMMAND_REPLY_TIMEOUT.as_secs()
+            ),
+        )),
+    }
+}
+
+fn reason_phrase(code: u16) -> &'static str {
+    match code {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        414 => "URI Too Long",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Error",
+    }
+}
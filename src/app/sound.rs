@@ -0,0 +1,37 @@
+// src/app/sound.rs
+// Plays the optional completion chime for batch pulls. Only compiled with the `sound` feature.
+
+use log::warn;
+use rodio::{source::SineWave, OutputStream, Sink, Source};
+use std::time::Duration;
+
+/// Plays a short two-tone chime on a background thread: rising for success, falling for failure.
+/// Missing audio devices are logged and otherwise ignored.
+pub fn play_completion_chime(success: bool) {
+    let tones: [f32; 2] = if success { [660.0, 880.0] } else { [440.0, 330.0] };
+    std::thread::spawn(move || {
+        // The stream must stay alive until playback ends
+        let (_stream, handle) = match OutputStream::try_default() {
+            Ok(output) => output,
+            Err(e) => {
+                warn!("No audio output available for the completion chime: {}", e);
+                return;
+            }
+        };
+        let sink = match Sink::try_new(&handle) {
+            Ok(sink) => sink,
+            Err(e) => {
+                warn!("Failed to create audio sink for the completion chime: {}", e);
+                return;
+            }
+        };
+        for freq in tones {
+            sink.append(
+                SineWave::new(freq)
+                    .take_duration(Duration::from_millis(150))
+                    .amplify(0.2),
+            );
+        }
+        sink.sleep_until_end();
+    });
+}
@@ -2,8 +2,12 @@
 // Defines state-related enums and structs for LlamaLift: application status, views, inter-thread messages, and table column/sort/width state.
 
 // Import necessary types from other modules within the app
-use crate::app::ollama::OllamaModel;
+use crate::app::model_updates::ModelUpdateState;
+use crate::app::ollama::{OllamaModel, OllamaShowResponse};
+use crate::app::remote_api::RemoteCommand;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{atomic::AtomicBool, Arc};
 
 // --- Application State Enums ---
 
@@ -17,6 +21,12 @@ pub enum AppStatus {
     ListingModels,
     /// Contains the model_name being deleted.
     DeletingModel(String),
+    /// Contains the model_name being loaded into memory.
+    LoadingModel(String),
+    /// Contains the model_name generating a test response in the prompt workbench.
+    Generating(String),
+    /// Contains the model_name being created from the prompt workbench.
+    CreatingModel(String),
     /// The last operation completed successfully.
     Success,
     /// Contains the error_message.
@@ -30,6 +40,8 @@ pub enum AppView {
     Download,
     /// The view for managing existing downloaded models.
     ManageModels,
+    /// The (advanced) view for testing a system prompt and creating a model from it.
+    Workbench,
 }
 
 /// Defines messages passed from background tasks (like Ollama interactions)
@@ -44,8 +56,114 @@ pub enum UpdateMessage {
     StatusText(String),
     /// A change in the overall application status.
     Status(AppStatus),
-    /// A new list of models received from the Ollama server.
-    ModelList(Vec<OllamaModel>),
+    /// A new list of models received from the Ollama server, with the host generation it was requested under.
+    ModelList(u64, Vec<OllamaModel>),
+    /// A per-model refresh (host generation, entries to add or replace, names to remove), merged into the current list.
+    ModelsChanged(u64, Vec<OllamaModel>, Vec<String>),
+    /// The model identifiers that failed to pull in the current/last batch (empty if all succeeded).
+    /// Sent after each failure and once more when the batch ends.
+    BatchFailures(Vec<String>),
+    /// The model identifiers of the current/last batch whose pull was cancelled (skipped or stopped).
+    /// Sent after each cancellation and once more when the batch ends.
+    BatchCancelled(Vec<String>),
+    /// The names of the models currently loaded into memory (from `/api/ps`), with the host generation.
+    RunningModels(u64, Vec<String>),
+    /// The `/api/show` details for a model (or the error message if fetching them failed), with the host generation.
+    ModelDetails(u64, String, Result<OllamaShowResponse, String>),
+    /// Per-model notes on whether a finished pull changed the model's digest (updated vs. up to date),
+    /// with the host generation.
+    PullDigestNotes(u64, Vec<String>),
+    /// The Ollama version reported by `/api/version`, with the host it was fetched from.
+    ServerVersion(String, String),
+    /// The latest LlamaLift release found by the update check: version and release page URL.
+    LatestRelease(String, String),
+    /// The unique footprint per model in the local model store (see `maintenance::unique_model_sizes`),
+    /// or the error message, with the host generation.
+    ReclaimableSizes(u64, Result<HashMap<String, u64>, String>),
+    /// Results of the model update check (host generation; model name, local digest checked, state).
+    ModelUpdateStates(u64, Vec<(String, String, ModelUpdateState)>),
+    /// A command received through the local remote-control API, with the channel to reply on whether it
+    /// was started (or the reason it was refused).
+    RemoteCommand(RemoteCommand, tokio::sync::oneshot::Sender<Result<(), String>>),
+    /// The prompt workbench's test response, or the error message if generating it failed.
+    WorkbenchOutput(Result<String, String>),
+    /// The next piece of a streamed workbench test response.
+    WorkbenchToken(String),
+    /// The generation speed of the last workbench test response, in tokens per second.
+    WorkbenchTokensPerSec(f64),
+    /// A finished step of the connection diagnostics, with the run it belongs to.
+    DiagnosticStep(u64, DiagnosticStep),
+}
+
+// --- Connection Diagnostics ---
+
+/// Names of the connection diagnostic steps, in the order they run.
+pub const DIAGNOSTIC_STEPS: [&str; 5] = ["Resolve URL", "DNS lookup", "TCP connect", "GET /api/version", "GET /api/tags"];
+
+/// The outcome of one connection diagnostic step (see `ollama::run_diagnostics_async`).
+#[derive(Debug, Clone)]
+pub struct DiagnosticStep {
+    /// Index into `DIAGNOSTIC_STEPS`.
+    pub index: usize,
+    /// What was found (Ok) or what went wrong (Err).
+    pub result: Result<String, String>,
+    pub elapsed_ms: u128,
+}
+
+// --- Prompt Workbench State ---
+
+/// The inputs and last output of the prompt workbench (kept for the session only).
+#[derive(Debug, Clone)]
+pub struct WorkbenchState {
+    pub base_model: String,
+    pub system_prompt: String,
+    pub temperature: f32,
+    pub top_p: f32,
+    /// Context window size; 0 keeps the model's default.
+    pub num_ctx: u32,
+    pub test_prompt: String,
+    /// Show the test response token by token (streamed `/api/generate`), with a Stop button.
+    pub stream: bool,
+    pub output: Option<Result<String, String>>,
+    pub tokens_per_sec: Option<f64>,
+    /// Set to stop the running streamed generation.
+    pub cancel_generation: Arc<AtomicBool>,
+    pub new_model_name: String,
+}
+
+/// Test prompt filled in when a freshly pulled model is opened in the workbench and no prompt is set.
+pub const DEFAULT_TEST_PROMPT: &str = "Introduce yourself in one sentence.";
+
+impl WorkbenchState {
+    /// Selects `model` for a quick test: keeps the system prompt and parameters, fills in the default
+    /// test prompt if there is none and clears the previous output.
+    pub fn prepare_test(&mut self, model: &str) {
+        self.base_model = model.to_string();
+        if self.test_prompt.trim().is_empty() {
+            self.test_prompt = DEFAULT_TEST_PROMPT.to_string();
+        }
+        self.output = None;
+        self.tokens_per_sec = None;
+    }
+}
+
+impl Default for WorkbenchState {
+    fn default() -> Self {
+        // Ollama's own defaults for the sampling parameters
+        Self {
+            base_model: String::new(),
+            system_prompt: String::new(),
+            temperature: 0.8,
+            top_p: 0.9,
+            num_ctx: 0,
+            test_prompt: String::new(),
+            stream: true,
+            output: None,
+            tokens_per_sec: None,
+            cancel_generation: Arc::new(AtomicBool::new(false)),
+            new_model_name: String::new(),
+        }
+    }
 }
 
 // --- Manage Models Table State ---
@@ -62,6 +180,10 @@ pub enum ModelColumn {
     Families,
     ParameterSize,
     QuantizationLevel,
+    LastUsed,
+    Lineage,
+    ContextLength,
+    Reclaimable,
     // Note: Actions (Delete, Copy, Edit buttons) column is handled separately in the table layout
 }
 
@@ -78,9 +200,26 @@ impl ModelColumn {
             ModelColumn::Families => "Families",
             ModelColumn::ParameterSize => "Parameter Size",
             ModelColumn::QuantizationLevel => "Quantization Level",
+            ModelColumn::LastUsed => "Last Used",
+            ModelColumn::Lineage => "Base / Fine-tune",
+            ModelColumn::ContextLength => "Context",
+            ModelColumn::Reclaimable => "Reclaimable",
         }
     }
 
+    /// Parses a column from a config key such as `Family`, `quantization_level` or `LastUsed`.
+    /// Case, underscores and dashes are ignored.
+    pub fn from_key(key: &str) -> Option<Self> {
+        let normalized: String = key
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .collect::<String>()
+            .to_lowercase();
+        Self::all()
+            .into_iter()
+            .find(|col| format!("{:?}", col).to_lowercase() == normalized)
+    }
+
     /// Returns a vector of all possible columns.
     pub fn all() -> Vec<Self> {
         vec![
@@ -93,6 +232,10 @@ impl ModelColumn {
             Self::Families,
             Self::ParameterSize,
             Self::QuantizationLevel,
+            Self::LastUsed,
+            Self::Lineage,
+            Self::ContextLength,
+            Self::Reclaimable,
         ]
     }
 }
@@ -2,14 +2,24 @@
 // Contains the UI drawing function for the Download Models view.
 
 use crate::app::{
+    catalog::large_models,
     config::MAX_MODEL_INPUTS,
     state::{AppStatus, UpdateMessage},
+    ui::widgets::draw_error_details,
+    ollama::{OllamaModel, PullInterrupted},
+    config::AppSettings,
+    utils::{apply_default_namespace, format_digest, resolve_model_alias, validate_model_identifier, with_default_tag},
     OllamaPullerApp,
 };
 use egui::{
-    Button, ProgressBar, ScrollArea, TextEdit, Ui,
+    Button, CollapsingHeader, Grid, ProgressBar, ScrollArea, TextEdit, Ui,
+};
+use log::{debug, error, info, warn};
+use std::collections::{HashMap, HashSet};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
 };
-use log::{error, info};
 use std::time::Duration;
 
 // --- View Drawing Functions ---
@@ -27,10 +37,11 @@ pub fn draw_download_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status
 
     ui.heading("Download Models");
     ui.separator();
-    ui.label("Enter model identifiers (e.g., 'llama3:latest', 'mistral'):");
+    ui.label("Enter model identifiers (e.g., 'llama3:latest', 'mistral', 'registry.example.com/library/model:tag'):");
 
     let mut add_new_input = false;
     let mut remove_index = None;
+    let mut swap_indices = None; // Rows to swap when moving an input up/down
     let num_inputs = app.model_inputs.len();
 
     // Scrollable area for model input fields
@@ -44,7 +55,30 @@ pub fn draw_download_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status
                         .hint_text("model:tag or model"); // Placeholder text
                     // Disable input field if pulling is in progress
                     ui.add_enabled(!is_pulling, text_edit);
+                    // Show what an alias stands for
+                    let input = app.model_inputs[i].trim();
+                    let resolved = resolve_model_alias(input, &app.settings.model_aliases);
+                    if resolved != input {
+                        ui.weak(format!("→ {}", resolved)).on_hover_text("Alias (see Settings → Model Aliases)");
+                    }
 
+                    // Move buttons to change the (sequential) pull order
+                    if num_inputs > 1 {
+                        if ui
+                            .add_enabled(!is_pulling && i > 0, Button::new("⬆").small())
+                            .on_hover_text("Pull this model earlier")
+                            .clicked()
+                        {
+                            swap_indices = Some((i - 1, i));
+                        }
+                        if ui
+                            .add_enabled(!is_pulling && i + 1 < num_inputs, Button::new("⬇").small())
+                            .on_hover_text("Pull this model later")
+                            .clicked()
+                        {
+                            swap_indices = Some((i, i + 1));
+                        }
+                    }
                     // Add remove button (-) if more than one input field exists
                     if num_inputs > 1 {
                         if ui
@@ -68,7 +102,10 @@ pub fn draw_download_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status
             }
         });
 
-    // Process remove/add actions after iterating
+    // Process move/remove/add actions after iterating
+    if let Some((a, b)) = swap_indices {
+        app.model_inputs.swap(a, b);
+    }
     if let Some(index) = remove_index {
         app.model_inputs.remove(index);
         // Ensure there's always at least one input field
@@ -80,11 +117,38 @@ pub fn draw_download_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status
         app.model_inputs.push("".to_string());
     }
 
+    // Point out entries that name the same model (e.g. 'llama3' and 'llama3:latest')
+    let inputs: Vec<String> = app
+        .model_inputs
+        .iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| resolve_model_input(s, &app.settings))
+        .collect();
+    let (_, duplicate_inputs) = split_duplicates(inputs);
+    if !duplicate_inputs.is_empty() {
+        ui.colored_label(
+            ui.visuals().warn_fg_color,
+            format!("⚠ Listed more than once (pulled only once): {}", duplicate_inputs.join(", ")),
+        );
+    }
+
+    if ui
+        .checkbox(&mut app.settings.test_after_download, "Test after download")
+        .on_hover_text("When the batch succeeds, open the Prompt Workbench with the first pulled model selected and a test prompt filled in")
+        .changed()
+    {
+        app.save_settings();
+    }
+
     ui.add_space(10.0); // Spacing
 
     // "Download Models" button
     if ui
-        .add_enabled(!is_pulling, Button::new("Download Models"))
+        .add_enabled(
+            !is_pulling && app.rt.is_some() && app.pending_pull_confirmation.is_none(),
+            Button::new("Download Models"),
+        )
         .clicked()
     {
         // Collect valid, non-empty model identifiers from input fields
@@ -93,10 +157,30 @@ pub fn draw_download_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status
             .iter()
             .map(|s| s.trim()) // Trim whitespace
             .filter(|s| !s.is_empty()) // Filter out empty strings
-            .map(|s| s.to_string())
+            .map(|s| {
+                let resolved = resolve_model_input(s, &app.settings);
+                if resolve_model_alias(s, &app.settings.model_aliases) != s {
+                    info!("Expanded alias '{}' to '{}'.", s, resolved);
+                }
+                resolved
+            })
+            .collect();
+        // Reject malformed identifiers up front (registry-prefixed names are fine)
+        let invalid_models: Vec<String> = models_to_pull
+            .iter()
+            .filter_map(|id| validate_model_identifier(id).err())
             .collect();
 
-        if models_to_pull.is_empty() {
+        if !invalid_models.is_empty() {
+            let error_msg = format!("Invalid model identifier(s): {}", invalid_models.join("; "));
+            error!("{}", error_msg);
+            let _ = app
+                .task_update_sender
+                .send(UpdateMessage::Log(format!("ERROR: {}", error_msg)));
+            *app.status_text.lock().unwrap() = format!("Error: {}", error_msg);
+            *app.status.lock().unwrap() = AppStatus::Error(error_msg);
+            app.failed_models.clear(); // The error is not about a previous batch
+        } else if models_to_pull.is_empty() {
             // Handle case where no valid models were entered
             error!("No valid model identifiers entered.");
             // Use task_update_sender
@@ -105,107 +189,19 @@ pub fn draw_download_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status
             ));
             *app.status_text.lock().unwrap() = "Error: No models entered.".to_string();
             *app.status.lock().unwrap() = AppStatus::Error("No models entered".to_string());
+            app.failed_models.clear();
         } else {
-            // Start the batch pull process
-            let num_models = models_to_pull.len();
-            info!("Starting batch pull for {} models.", num_models);
-            // Use task_update_sender
-            let _ = app.task_update_sender.send(UpdateMessage::Log(format!(
-                "INFO: Starting batch pull for {} models.",
-                num_models
-            )));
-
-            // Get necessary resources for the async task
-            let current_config = app.get_current_config();
-            let sender = app.task_update_sender.clone(); // Clone sender for the task
-            let rt_handle = app.rt.clone(); // Clone Tokio runtime handle
-            let status_arc = app.status.clone(); // Clone Arc for status
-
-            // Set initial status for pulling
-            // Use 1-based indexing for UI display (current model number)
-            *status_arc.lock().unwrap() = AppStatus::Pulling(1, num_models);
-            *app.progress.lock().unwrap() = 0.0; // Reset progress
-
-            // Spawn the asynchronous task to perform the pull
-            rt_handle.spawn(async move {
-                let mut overall_success = true; // Track if all pulls succeed
-                let mut last_error_msg = String::new(); // Store the last error message
-
-                // Iterate through models and pull them sequentially
-                for (index, model_id) in models_to_pull.iter().enumerate() {
-                    // Use 1-based index for status messages and progress calculation
-                    let current_model_num = index + 1;
-                    let status_msg = format!(
-                        "Pulling model {}/{} ({})",
-                        current_model_num, num_models, model_id
-                    );
-                    info!("{}", status_msg); // Log start of individual pull
-
-                    // Send updates to UI thread
-                    let _ = sender.send(UpdateMessage::Status(AppStatus::Pulling(
-                        current_model_num, // Update current model index (1-based)
-                        num_models,
-                    )));
-                    // Send the specific model name being pulled as status text
-                    let _ = sender.send(UpdateMessage::StatusText(format!(
-                        "Pulling: {}",
-                        model_id
-                    )));
-                    let _ = sender.send(UpdateMessage::Progress(0.0)); // Reset progress for this model
-
-                    // Call the async pull function
-                    match crate::app::ollama::pull_model_async(
-                        model_id,
-                        &current_config,
-                        sender.clone(),
-                    )
-                    .await
-                    {
-                        Ok(_) => {
-                            // Handle successful pull
-                            info!("Successfully pulled model '{}'.", model_id);
-                            let _ = sender.send(UpdateMessage::Log(format!(
-                                "INFO: Successfully pulled model '{}'.",
-                                model_id
-                            )));
-                            // Set progress to 1.0 explicitly for the completed model before delay
-                            // This ensures the overall progress bar updates correctly
-                            if current_model_num < num_models {
-                                let _ = sender.send(UpdateMessage::Progress(1.0));
-                            }
-                            tokio::time::sleep(Duration::from_millis(300)).await;
-                        }
-                        Err(e) => {
-                            // Handle failed pull
-                            error!("Failed to pull model '{}': {}", model_id, e);
-                            let err_log =
-                                format!("ERROR: Failed to pull model '{}': {}", model_id, e);
-                            let _ = sender.send(UpdateMessage::Log(err_log));
-                            overall_success = false; // Mark batch as failed
-                            last_error_msg = e.to_string(); // Store error message
-                        }
-                    }
-                }
-
-                // Update final status after batch completes
-                if overall_success {
-                    info!("Batch pull completed successfully.");
-                    let _ = sender.send(UpdateMessage::StatusText(
-                        "Batch pull completed successfully.".to_string(),
-                    ));
-                    let _ = sender.send(UpdateMessage::Status(AppStatus::Success));
-                    let _ = sender.send(UpdateMessage::Progress(1.0)); // Set progress to 100%
-                } else {
-                    error!("Batch pull finished with errors.");
-                    let final_status_text = format!(
-                        "Batch pull finished with errors. Last error: {}",
-                        last_error_msg
-                    );
-                    let _ = sender.send(UpdateMessage::StatusText(final_status_text));
-                    let _ = sender.send(UpdateMessage::Status(AppStatus::Error(last_error_msg)));
-                    let _ = sender.send(UpdateMessage::Progress(0.0)); // Reset progress on error
-                }
-            });
+            let has_large_models = app.settings.confirm_large_pulls
+                && !large_models(&models_to_pull, app.settings.large_pull_threshold_gib).is_empty();
+            let is_large_batch = app.settings.confirm_batch_size
+                && models_to_pull.len() > app.settings.batch_size_confirm_threshold;
+            if has_large_models || is_large_batch {
+                // Ask first; the confirmation window starts the batch (see app/mod.rs)
+                info!("Asking for confirmation before pulling {} model(s).", models_to_pull.len());
+                app.pending_pull_confirmation = Some(models_to_pull);
+            } else {
+                start_batch_pull(app, models_to_pull);
+            }
         }
     }
     ui.separator();
@@ -235,10 +231,21 @@ pub fn draw_download_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status
             ui.add_sized(
                 [ui.available_width(), ui.spacing().interact_size.y],
                 overall_progress_bar,
-            );
+            )
+            .on_hover_ui(|ui| draw_batch_breakdown(app, ui, *current));
 
-            // --- Current Item Progress (Only if more than one model total) ---
-            if *total > 1 {
+            // --- Current Item Progress ---
+            if !app.settings.stream_pulls {
+                // Non-streaming pulls report nothing until the model is done
+                ui.add_space(4.0);
+                ui.add_sized(
+                    [ui.available_width(), ui.spacing().interact_size.y],
+                    ProgressBar::new(0.0)
+                        .animate(true)
+                        .text(format!("{} (non-streaming, no live progress)", status_txt)),
+                );
+            } else if *total > 1 {
+                // Only if more than one model total
                 ui.add_space(4.0); // Add some space between the bars
 
                 let current_item_text = format!("{} - {:.1}%", status_txt, progress_val * 100.0);
@@ -251,10 +258,37 @@ pub fn draw_download_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status
                     current_item_progress_bar,
                 );
             }
+
+            // --- Queue ---
+            if *total > 1 {
+                ui.add_space(4.0);
+                draw_batch_queue(app, ui, *current, progress_val);
+            }
         }
         AppStatus::Error(e) => {
             // Display error message in red
             ui.colored_label(ui.visuals().error_fg_color, format!("Error: {}", e));
+            draw_error_details(ui, "download_error_details", e);
+            for note in &app.pull_digest_notes {
+                ui.label(note);
+            }
+
+            // Offer to copy the models that failed in the last batch, e.g. for a retry
+            if !app.failed_models.is_empty() {
+                let button_text = format!("📋 Copy failed models ({})", app.failed_models.len());
+                if ui
+                    .button(button_text)
+                    .on_hover_text("Copy the failed model names (one per line) to the clipboard")
+                    .clicked()
+                {
+                    ui.ctx().copy_text(app.failed_models.join("\n"));
+                    info!("Copied {} failed model name(s) to clipboard.", app.failed_models.len());
+                    let _ = app.task_update_sender.send(UpdateMessage::Log(format!(
+                        "INFO: Copied {} failed model name(s) to clipboard.",
+                        app.failed_models.len()
+                    )));
+                }
+            }
         }
         AppStatus::Success => {
             // Display success message and maybe a full progress bar
@@ -266,9 +300,450 @@ pub fn draw_download_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status
                 [ui.available_width(), ui.spacing().interact_size.y],
                 progress_bar,
             );
+            for note in &app.pull_digest_notes {
+                ui.label(note);
+            }
         }
         _ => {
             ui.label(app.status_text.lock().unwrap().clone());
         }
     }
 }
+
+// Starts pulling the given (already validated) models sequentially in a background task.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * models_to_pull - The model identifiers to pull, in order.
+pub fn start_batch_pull(app: &mut OllamaPullerApp, models_to_pull: Vec<String>) {
+    // Skip models queued twice. Only one batch runs at a time: every caller starts one only while idle.
+    let (models_to_pull, duplicates) = split_duplicates(models_to_pull);
+    for duplicate in &duplicates {
+        warn!("Skipping '{}': it is already queued for pulling.", duplicate);
+        let _ = app.task_update_sender.send(UpdateMessage::Log(format!(
+            "WARN: Skipping '{}': it is already queued for pulling.",
+            duplicate
+        )));
+    }
+
+    let num_models = models_to_pull.len();
+    info!("Starting batch pull for {} models.", num_models);
+    // Use task_update_sender
+    let _ = app.task_update_sender.send(UpdateMessage::Log(format!(
+        "INFO: Starting batch pull for {} models.",
+        num_models
+    )));
+    if !app.settings.default_namespace.trim().is_empty() {
+        let resolved = models_to_pull.join(", ");
+        info!("Resolved model names: {}", resolved);
+        let _ = app
+            .task_update_sender
+            .send(UpdateMessage::Log(format!("INFO: Resolved model names: {}", resolved)));
+    }
+
+    // Get necessary resources for the async task
+    let current_config = app.get_current_config();
+    let sender = app.task_update_sender.clone(); // Clone sender for the task
+    let status_arc = app.status.clone(); // Clone Arc for status
+    let per_model_refresh = app.settings.per_model_refresh; // Merge only the pulled models' entries
+    let refresh_after_pull = app.settings.refresh_after_pull;
+
+    // Set initial status for pulling
+    // Use 1-based indexing for UI display (current model number)
+    *status_arc.lock().unwrap() = AppStatus::Pulling(1, num_models);
+    *app.progress.lock().unwrap() = 0.0; // Reset progress
+    app.failed_models.clear(); // Forget failures from the previous batch
+    app.cancelled_models.clear();
+    app.batch_models = models_to_pull.clone(); // For the progress breakdown tooltip
+    app.pull_digest_notes.clear();
+    // One cancel flag per model, set by its ✕ button in the queue
+    app.batch_cancel_flags = models_to_pull
+        .iter()
+        .map(|model_id| (model_id.clone(), Arc::new(AtomicBool::new(false))))
+        .collect();
+    let cancel_flags = app.batch_cancel_flags.clone();
+
+    // Spawn the asynchronous task to perform the pull
+    app.spawn_task(async move {
+        let mut overall_success = true; // Track if all pulls succeed
+        let mut last_error_msg = String::new(); // Store the last error message
+        let mut failed_models: Vec<String> = Vec::new(); // Models that failed to pull
+        let mut cancelled_models: Vec<String> = Vec::new(); // Models skipped or stopped by the user
+
+        // Snapshot the digests before pulling, to tell real updates from "already up to date"
+        let digests_before = match crate::app::ollama::list_models_async(&current_config, sender.clone()).await {
+            Ok(models) => Some(digest_snapshot(&models)),
+            Err(e) => {
+                debug!("Could not list models before the pull, skipping the update check: {}", e);
+                None
+            }
+        };
+
+        // Iterate through models and pull them sequentially
+        for (index, model_id) in models_to_pull.iter().enumerate() {
+            // Use 1-based index for status messages and progress calculation
+            let current_model_num = index + 1;
+            let cancel = cancel_flags.get(model_id).cloned().unwrap_or_default();
+            if cancel.load(Ordering::Relaxed) {
+                info!("Skipping '{}': cancelled before its pull started.", model_id);
+                let _ = sender.send(UpdateMessage::Log(format!(
+                    "INFO: Skipping '{}': cancelled before its pull started.",
+                    model_id
+                )));
+                cancelled_models.push(model_id.clone());
+                let _ = sender.send(UpdateMessage::BatchCancelled(cancelled_models.clone()));
+                continue;
+            }
+            let status_msg = format!(
+                "Pulling model {}/{} ({})",
+                current_model_num, num_models, model_id
+            );
+            info!("{}", status_msg); // Log start of individual pull
+
+            // Send updates to UI thread; stop the batch if nobody is listening anymore (shutdown)
+            if sender
+                .send(UpdateMessage::Status(AppStatus::Pulling(
+                    current_model_num, // Update current model index (1-based)
+                    num_models,
+                )))
+                .is_err()
+            {
+                debug!("Update channel closed, abandoning the batch before '{}'.", model_id);
+                return;
+            }
+            // Send the specific model name being pulled as status text
+            let _ = sender.send(UpdateMessage::StatusText(format!(
+                "Pulling: {}",
+                model_id
+            )));
+            let _ = sender.send(UpdateMessage::Progress(0.0)); // Reset progress for this model
+
+            // Call the async pull function
+            match crate::app::ollama::pull_model_async(
+                model_id,
+                &current_config,
+                sender.clone(),
+                cancel,
+            )
+            .await
+            {
+                Ok(_) => {
+                    // Handle successful pull
+                    info!("Successfully pulled model '{}'.", model_id);
+                    let _ = sender.send(UpdateMessage::Log(format!(
+                        "INFO: Successfully pulled model '{}'.",
+                        model_id
+                    )));
+                    // Set progress to 1.0 explicitly for the completed model before delay
+                    // This ensures the overall progress bar updates correctly
+                    if current_model_num < num_models {
+                        let _ = sender.send(UpdateMessage::Progress(1.0));
+                    }
+                    tokio::time::sleep(Duration::from_millis(300)).await;
+                }
+                Err(e) if e.downcast_ref::<PullInterrupted>() == Some(&PullInterrupted::ChannelClosed) => {
+                    debug!("Update channel closed, abandoning the batch during '{}'.", model_id);
+                    return;
+                }
+                Err(e) if e.downcast_ref::<PullInterrupted>() == Some(&PullInterrupted::Cancelled) => {
+                    // Only this model stops; the batch goes on with the next one
+                    info!("Cancelled the pull of '{}'.", model_id);
+                    let _ = sender.send(UpdateMessage::Log(format!("INFO: Cancelled the pull of '{}'.", model_id)));
+                    cancelled_models.push(model_id.clone());
+                    let _ = sender.send(UpdateMessage::BatchCancelled(cancelled_models.clone()));
+                }
+                Err(e) => {
+                    // Handle failed pull
+                    error!("Failed to pull model '{}': {}", model_id, e);
+                    let err_log =
+                        format!("ERROR: Failed to pull model '{}': {}", model_id, e);
+                    let _ = sender.send(UpdateMessage::Log(err_log));
+                    overall_success = false; // Mark batch as failed
+                    last_error_msg = e.to_string(); // Store error message
+                    failed_models.push(model_id.clone());
+                    // Report failures as they happen for the progress breakdown
+                    let _ = sender.send(UpdateMessage::BatchFailures(failed_models.clone()));
+                }
+            }
+        }
+
+        // Compare digests after the pull and (optionally) update the Manage view with the fresh list,
+        // unless nothing was pulled
+        let not_pulled: Vec<String> = failed_models.iter().chain(&cancelled_models).cloned().collect();
+        let any_pulled = not_pulled.len() < models_to_pull.len();
+        let update_list = refresh_after_pull && any_pulled;
+        if digests_before.is_some() || update_list {
+            match crate::app::ollama::list_models_async(&current_config, sender.clone()).await {
+                Ok(models) => {
+                    let notes = match &digests_before {
+                        Some(digests_before) => describe_digest_changes(
+                            &models_to_pull,
+                            &not_pulled,
+                            digests_before,
+                            &digest_snapshot(&models),
+                        ),
+                        None => Vec::new(),
+                    };
+                    for note in &notes {
+                        info!("{}", note);
+                        let _ = sender.send(UpdateMessage::Log(format!("INFO: {}", note)));
+                    }
+                    match pulled_entries(&models, &models_to_pull, &not_pulled) {
+                        _ if !update_list => debug!("Not updating the model list after the pull (disabled or nothing pulled)."),
+                        Some(pulled) if per_model_refresh => {
+                            let _ = sender.send(UpdateMessage::ModelsChanged(current_config.host_generation, pulled, Vec::new()));
+                        }
+                        _ => {
+                            if per_model_refresh {
+                                debug!("Not all pulled models found in the list, replacing the whole list.");
+                            }
+                            let _ = sender.send(UpdateMessage::ModelList(current_config.host_generation, models));
+                        }
+                    }
+                    let _ = sender.send(UpdateMessage::PullDigestNotes(current_config.host_generation, notes));
+                }
+                Err(e) => debug!("Could not list models after the pull, skipping the update check and list update: {}", e),
+            }
+        }
+
+        // Report which models failed or were cancelled (if any) before the final status
+        let _ = sender.send(UpdateMessage::BatchFailures(failed_models));
+        let cancelled_count = cancelled_models.len();
+        let _ = sender.send(UpdateMessage::BatchCancelled(cancelled_models));
+
+        // Update final status after batch completes; cancelled models count neither as pulled nor as failed
+        if cancelled_count == num_models {
+            info!("Batch pull cancelled.");
+            let _ = sender.send(UpdateMessage::StatusText("Batch pull cancelled.".to_string()));
+            let _ = sender.send(UpdateMessage::Status(AppStatus::Idle));
+            let _ = sender.send(UpdateMessage::Progress(0.0));
+        } else if overall_success {
+            let status_text = if cancelled_count > 0 {
+                format!("Batch pull completed successfully ({} cancelled).", cancelled_count)
+            } else {
+                "Batch pull completed successfully.".to_string()
+            };
+            info!("{}", status_text);
+            let _ = sender.send(UpdateMessage::StatusText(status_text));
+            let _ = sender.send(UpdateMessage::Status(AppStatus::Success));
+            let _ = sender.send(UpdateMessage::Progress(1.0)); // Set progress to 100%
+        } else {
+            error!("Batch pull finished with errors.");
+            let final_status_text = format!(
+                "Batch pull finished with errors. Last error: {}",
+                last_error_msg
+            );
+            let _ = sender.send(UpdateMessage::StatusText(final_status_text));
+            let _ = sender.send(UpdateMessage::Status(AppStatus::Error(last_error_msg)));
+            let _ = sender.send(UpdateMessage::Progress(0.0)); // Reset progress on error
+        }
+    });
+}
+
+// Turns an entered model into the identifier to pull: expands an alias, then applies the default namespace.
+pub fn resolve_model_input(input: &str, settings: &AppSettings) -> String {
+    apply_default_namespace(
+        resolve_model_alias(input, &settings.model_aliases),
+        &settings.default_namespace,
+    )
+}
+
+// Maps each listed model name to its digest.
+fn digest_snapshot(models: &[OllamaModel]) -> HashMap<String, String> {
+    models
+        .iter()
+        .map(|m| (m.name.clone(), m.digest.clone()))
+        .collect()
+}
+
+// Splits model identifiers into the ones to pull and the duplicates to skip, i.e. repeats within the list
+// (compared with the default tag applied, so 'llama3' matches 'llama3:latest').
+// Keeps the first occurrence and the order.
+pub fn split_duplicates(models: Vec<String>) -> (Vec<String>, Vec<String>) {
+    let mut seen: HashSet<String> = HashSet::new();
+    models.into_iter().partition(|id| seen.insert(with_default_tag(id)))
+}
+
+// Picks the list entries of the successfully pulled models, for a per-model refresh.
+// Returns None if any of them can't be found by name (e.g. the server normalized it differently),
+// in which case the whole list should be used instead.
+fn pulled_entries(models: &[OllamaModel], pulled: &[String], failed: &[String]) -> Option<Vec<OllamaModel>> {
+    pulled
+        .iter()
+        .filter(|id| !failed.contains(id))
+        .map(|id| {
+            let name = with_default_tag(id);
+            models.iter().find(|m| m.name == name).cloned()
+        })
+        .collect()
+}
+
+// Describes, per successfully pulled model, whether the pull changed its digest.
+//
+// # Arguments
+//
+// * pulled - The model identifiers of the batch, as entered (a missing tag means `:latest`).
+// * failed - The identifiers that failed to pull (skipped).
+// * before / after - Digest snapshots from before and after the batch.
+fn describe_digest_changes(
+    pulled: &[String],
+    failed: &[String],
+    before: &HashMap<String, String>,
+    after: &HashMap<String, String>,
+) -> Vec<String> {
+    pulled
+        .iter()
+        .filter(|id| !failed.contains(id))
+        .filter_map(|id| {
+            let name = with_default_tag(id);
+            let new_digest = after.get(&name)?;
+            Some(match before.get(&name) {
+                None => format!("{}: newly downloaded.", name),
+                Some(old_digest) if old_digest == new_digest => {
+                    format!("{}: already up to date.", name)
+                }
+                Some(old_digest) => format!(
+                    "{}: updated ({} → {}).",
+                    name,
+                    format_digest(old_digest, 12, true),
+                    format_digest(new_digest, 12, true)
+                ),
+            })
+        })
+        .collect()
+}
+
+// State of one model in the running batch.
+#[derive(Clone, Copy, PartialEq)]
+enum BatchItemState {
+    Done,
+    Failed,
+    Cancelled,
+    Pulling,
+    Queued,
+}
+
+// Returns the state of the batch model at 1-based position `model_num`, given the model being pulled.
+// A model whose ✕ was clicked shows as cancelled right away (the pull notices within a moment).
+fn batch_item_state(app: &OllamaPullerApp, model_id: &str, model_num: usize, current: usize) -> BatchItemState {
+    let cancel_requested = app
+        .batch_cancel_flags
+        .get(model_id)
+        .is_some_and(|flag| flag.load(Ordering::Relaxed));
+    if app.cancelled_models.iter().any(|cancelled| cancelled == model_id) || (cancel_requested && model_num >= current) {
+        BatchItemState::Cancelled
+    } else if model_num < current {
+        if app.failed_models.iter().any(|failed| failed == model_id) {
+            BatchItemState::Failed
+        } else {
+            BatchItemState::Done
+        }
+    } else if model_num == current {
+        BatchItemState::Pulling
+    } else {
+        BatchItemState::Queued
+    }
+}
+
+// Draws the label for a batch item state.
+fn draw_batch_item_state(ui: &mut Ui, state: BatchItemState) {
+    match state {
+        BatchItemState::Failed => ui.colored_label(ui.visuals().error_fg_color, "failed"),
+        BatchItemState::Done => ui.label("done"),
+        BatchItemState::Cancelled => ui.weak("cancelled"),
+        BatchItemState::Pulling => ui.strong("pulling"),
+        BatchItemState::Queued => ui.weak("queued"),
+    };
+}
+
+// Cancels one model of the running batch by setting its cancel flag: a queued model is skipped, the one
+// being pulled stops (its partial download stays in the store, so pulling it again resumes).
+fn cancel_batch_item(app: &OllamaPullerApp, model_id: &str) {
+    let Some(flag) = app.batch_cancel_flags.get(model_id) else { return };
+    flag.store(true, Ordering::Relaxed);
+    info!("Cancelling the pull of '{}'.", model_id);
+    let _ = app
+        .task_update_sender
+        .send(UpdateMessage::Log(format!("INFO: Cancelling the pull of '{}'.", model_id)));
+}
+
+// Draws the per-model state of the running batch (shown when hovering the overall progress bar).
+//
+// # Arguments
+//
+// * app - Reference to the main application state (OllamaPullerApp).
+// * ui - Mutable reference to the egui UI context of the tooltip.
+// * current - The 1-based number of the model currently being pulled.
+fn draw_batch_breakdown(app: &OllamaPullerApp, ui: &mut Ui, current: usize) {
+    Grid::new("batch_breakdown_grid")
+        .num_columns(2)
+        .spacing([10.0, 2.0])
+        .show(ui, |ui| {
+            for (index, model_id) in app.batch_models.iter().enumerate() {
+                ui.label(model_id);
+                draw_batch_item_state(ui, batch_item_state(app, model_id, index + 1, current));
+                ui.end_row();
+            }
+        });
+}
+
+// Draws the queue of the running batch below the progress bars: every model in pull order with its
+// state, the progress of the one being pulled, and a ✕ button to cancel a single model (the others
+// keep going). Kept apart from the input fields, which may have been edited into something else
+// (aliases, duplicates) than what is actually pulled.
+//
+// # Arguments
+//
+// * app - Reference to the main application state (OllamaPullerApp).
+// * ui - Mutable reference to the egui UI context for drawing.
+// * current - The 1-based number of the model currently being pulled.
+// * progress_val - The progress of the current model (0.0 to 1.0).
+fn draw_batch_queue(app: &OllamaPullerApp, ui: &mut Ui, current: usize, progress_val: f32) {
+    let remaining = app.batch_models.len().saturating_sub(current);
+    CollapsingHeader::new(format!("Queue ({} remaining after the current model)", remaining))
+        .id_salt("batch_queue")
+        .default_open(true)
+        .show(ui, |ui| {
+            ScrollArea::vertical()
+                .id_salt("batch_queue_scroll")
+                .max_height(150.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    Grid::new("batch_queue_grid")
+                        .num_columns(4)
+                        .spacing([10.0, 2.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for (index, model_id) in app.batch_models.iter().enumerate() {
+                                let state = batch_item_state(app, model_id, index + 1, current);
+                                ui.weak(format!("{}.", index + 1));
+                                if state == BatchItemState::Pulling {
+                                    ui.strong(model_id);
+                                } else {
+                                    ui.label(model_id);
+                                }
+                                ui.horizontal(|ui| {
+                                    draw_batch_item_state(ui, state);
+                                    if state == BatchItemState::Pulling && app.settings.stream_pulls {
+                                        ui.label(format!("{:.1}%", progress_val * 100.0));
+                                    }
+                                });
+                                if matches!(state, BatchItemState::Pulling | BatchItemState::Queued) {
+                                    let hover_text = if state == BatchItemState::Pulling {
+                                        "Stop pulling this model and continue with the next one"
+                                    } else {
+                                        "Skip this model"
+                                    };
+                                    if ui.small_button("✕").on_hover_text(hover_text).clicked() {
+                                        cancel_batch_item(app, model_id);
+                                    }
+                                } else {
+                                    ui.label("");
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+}
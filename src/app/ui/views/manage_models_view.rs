@@ -2,14 +2,23 @@
 // Contains the UI drawing function for the Manage Models view.
 
 use crate::app::{
+    config::REFRESH_DEBOUNCE_MS,
+    ollama::OllamaModel,
     state::{AppStatus, ColumnState, ModelColumn, SortDirection}, // Removed UpdateMessage
+    ui::widgets::draw_error_details,
+    utils::{base_model_name, format_bytes_grouped, format_size, model_tag},
     OllamaPullerApp,
 };
 use egui::{
-    Button, Layout, RichText, Ui,
+    Button, CollapsingHeader, Color32, ComboBox, Grid, Label, Layout, RichText, ScrollArea, Sense,
+    TextEdit, TextWrapMode, Ui,
 };
-use egui_extras::{Column, TableBuilder};
+use egui_extras::{Column, TableBuilder, TableRow};
 use log::{debug, info};
+use chrono_tz::Tz;
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 // --- View Drawing Functions ---
 
@@ -28,8 +37,25 @@ pub fn draw_manage_models_view(
 ) {
     let is_busy_listing = *current_status == AppStatus::ListingModels;
     let is_busy_deleting = matches!(current_status, AppStatus::DeletingModel(_));
-    let is_otherwise_busy = matches!(current_status, AppStatus::Pulling(_, _));
-    let is_busy = is_busy_listing || is_busy_deleting || is_otherwise_busy;
+    let is_busy_loading = matches!(current_status, AppStatus::LoadingModel(_));
+    // Any other running operation (a pull, or a workbench generation or create) also blocks the actions
+    let is_busy = !matches!(current_status, AppStatus::Idle | AppStatus::Success | AppStatus::Error(_));
+    let is_otherwise_busy = is_busy && !is_busy_listing && !is_busy_deleting && !is_busy_loading;
+
+    // Custom stripe color (striped table rows and group grids use faint_bg_color)
+    if let Some([r, g, b]) = app.settings.table_stripe_color {
+        ui.visuals_mut().faint_bg_color = Color32::from_rgb(r, g, b);
+    }
+
+    // Debounce the refresh button: briefly disable it after a click
+    let refresh_debounce = Duration::from_millis(REFRESH_DEBOUNCE_MS);
+    let refresh_cooling_down = match app.last_refresh_click {
+        Some(clicked_at) if clicked_at.elapsed() < refresh_debounce => {
+            ui.ctx().request_repaint_after(refresh_debounce - clicked_at.elapsed()); // Re-enable on time
+            true
+        }
+        _ => false,
+    };
 
     ui.heading("Manage Downloaded Models");
     ui.separator();
@@ -41,23 +67,103 @@ pub fn draw_manage_models_view(
         ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
             // Refresh button
             if ui
-                .add_enabled(!is_busy, Button::new("🔄 Refresh List"))
+                .add_enabled(
+                    !is_busy && !refresh_cooling_down && app.rt.is_some(),
+                    Button::new("🔄 Refresh List"),
+                )
                 .clicked()
             {
+                app.last_refresh_click = Some(Instant::now());
                 app.refresh_model_list(); // Trigger refresh action
             }
+            // Model list, loaded models and server version together
+            if ui
+                .add_enabled(
+                    !is_busy && !refresh_cooling_down && app.rt.is_some(),
+                    Button::new("🔄 Refresh All"),
+                )
+                .on_hover_text("Fetch the model list, the loaded models and the server version at once")
+                .clicked()
+            {
+                app.last_refresh_click = Some(Instant::now());
+                app.refresh_all();
+            }
+            // Compare the tags' digests with the registry (background, doesn't block other operations)
+            if app.model_update_check_running {
+                ui.spinner();
+            } else if ui
+                .add_enabled(
+                    !app.manage_view_cache.is_empty() && app.rt.is_some(),
+                    Button::new("⬆ Check Updates"),
+                )
+                .on_hover_text("Ask registry.ollama.ai whether newer versions of these tags exist (nothing is downloaded). Models with updates get a ⬆ badge.")
+                .clicked()
+            {
+                app.check_model_updates(true);
+            }
+            // Copy the table as shown (visible columns, current sort) for docs/issues
+            if ui
+                .add_enabled(!app.manage_view_cache.is_empty(), Button::new("📋 Markdown"))
+                .on_hover_text("Copy the table (visible columns, current sort) as Markdown")
+                .clicked()
+            {
+                let visible_columns: Vec<ModelColumn> = app
+                    .model_column_states
+                    .iter()
+                    .filter(|cs| cs.visible)
+                    .map(|cs| cs.column.clone())
+                    .collect();
+                ui.ctx().copy_text(models_as_markdown(&app.manage_view_cache, &visible_columns));
+                info!("Copied {} models as a Markdown table.", app.manage_view_cache.len());
+            }
+            // Compare the models ticked in the selection column
+            let num_selected = app.selected_models.len();
+            // Selected models stay selected while a filter hides them
+            let num_hidden = app
+                .selected_models
+                .iter()
+                .filter(|name| !app.manage_view_cache.iter().any(|m| &m.name == *name))
+                .count();
+            let compare_hover_text = if num_hidden > 0 {
+                format!("Compare the selected models side by side ({} of them hidden by the current filter)", num_hidden)
+            } else {
+                "Compare the selected models side by side. Tick the boxes or click rows to select models.".to_string()
+            };
+            if ui
+                .add_enabled(num_selected >= 2, Button::new(format!("Compare ({})", num_selected)))
+                .on_hover_text(compare_hover_text)
+                .clicked()
+            {
+                info!("Opening comparison for {} models.", num_selected);
+                app.show_compare_window = true;
+            }
+            // Narrow the list to what is in memory right now (kept up to date by the running-models poll)
+            ui.checkbox(&mut app.show_only_loaded, format!("Only loaded ({})", app.running_models.len()))
+                .on_hover_text("Show only the models currently loaded into memory (from /api/ps)");
+            // Toggle between the flat table and the grouped-by-base-name view
+            ui.checkbox(&mut app.model_group_by_base, "Group tags")
+                .on_hover_text("Group tags like 'llama3:8b' and 'llama3:70b' under 'llama3'");
             // Display spinners and status text based on current activity
             if is_busy_listing {
                 ui.spinner();
-                ui.label("Listing...");
+                ui.label("Refreshing...");
             } else if is_busy_deleting {
                 ui.spinner();
                 if let AppStatus::DeletingModel(name) = current_status {
                     ui.label(format!("Deleting {}...", name));
                 }
+            } else if is_busy_loading {
+                ui.spinner();
+                if let AppStatus::LoadingModel(name) = current_status {
+                    ui.label(format!("Loading {}...", name));
+                }
             } else if is_otherwise_busy {
                 ui.spinner();
-                ui.label("Busy downloading..."); // Indicate pull is in progress
+                match current_status {
+                    AppStatus::Generating(name) => ui.label(format!("Generating with {}...", name)),
+                    AppStatus::CreatingModel(name) => ui.label(format!("Creating {}...", name)),
+                    _ => ui.label("Busy downloading..."), // Indicate pull is in progress
+                };
             } else if let AppStatus::Error(e) = current_status {
                 // Show an error indicator if the last operation failed
                 ui.colored_label(ui.visuals().error_fg_color, "!")
@@ -74,6 +180,20 @@ pub fn draw_manage_models_view(
     let original_list_is_empty = models_lock.is_empty();
     drop(models_lock); // Release lock immediately
 
+    if let AppStatus::Error(e) = current_status {
+        match error_notice(current_status, original_list_is_empty, app.error_toast_dismissed) {
+            ErrorNotice::Inline => {
+                // Full error text for the "!" indicator above
+                ui.colored_label(ui.visuals().error_fg_color, "The last operation failed.");
+                draw_error_details(ui, "manage_error_details", e);
+                ui.separator();
+            }
+            // The last good list stays usable, so only point the error out on the side
+            ErrorNotice::Toast => draw_error_toast(app, ui.ctx(), e),
+            ErrorNotice::Hidden => {}
+        }
+    }
+
     if original_list_is_empty {
         // Display messages if the list is empty based on the current status
         if matches!(current_status, AppStatus::Error(_)) {
@@ -98,12 +218,27 @@ pub fn draw_manage_models_view(
         }
         // --- End Cache Rebuild ---
 
+        // Optional disk usage overview (collapsed by default so it doesn't crowd the table)
+        draw_size_overview(app, ui);
+        draw_model_picker(app, ui);
+
+        if app.show_only_loaded && app.manage_view_cache.is_empty() {
+            ui.label("No models are loaded right now. Untick 'Only loaded' to see all models.");
+            return;
+        }
+
+        if app.model_group_by_base {
+            draw_grouped_models(app, ui, is_busy);
+            return;
+        }
+
         // --- Build the Table ---
         // Now we can safely hopefully borrow immutably
-        let visible_columns: Vec<&ColumnState> = app
+        let visible_columns: Vec<ColumnState> = app
             .model_column_states
             .iter()
             .filter(|cs| cs.visible)
+            .cloned()
             .collect();
 
         debug!( // Log after potential rebuild
@@ -113,136 +248,143 @@ pub fn draw_manage_models_view(
         );
 
         let num_visible_data_columns = visible_columns.len();
-        let delete_button_width = 60.0;
-        let default_column_width = 120.0;
 
         // Calculate row height *before* the TableBuilder borrows ui mutably
         let row_height = ui.text_style_height(&egui::TextStyle::Body);
+        // With wrapping on, rows grow to fit their tallest wrapped cell (based on the stored column widths)
+        let wrap_text = app.settings.table_wrap_text;
+        let wrapped_row_heights: Option<Vec<f32>> = wrap_text.then(|| {
+            let font_id = egui::TextStyle::Body.resolve(ui.style());
+            let cell_padding = ui.spacing().item_spacing.x;
+            app.manage_view_cache
+                .iter()
+                .map(|model| {
+                    visible_columns
+                        .iter()
+                        .map(|cs| {
+                            let wrap_width = (cs.width.unwrap_or(DEFAULT_COLUMN_WIDTH) - cell_padding).max(1.0);
+                            ui.fonts(|fonts| {
+                                fonts
+                                    .layout(cell_text(model, &cs.column), font_id.clone(), Color32::PLACEHOLDER, wrap_width)
+                                    .size()
+                                    .y
+                            })
+                        })
+                        .fold(row_height, f32::max)
+                })
+                .collect()
+        });
 
-        // Temporary variable to store the column to hide after the table interaction
-        let mut column_to_hide: Option<ModelColumn> = None;
+        // Temporary variables to store the column to hide / row action after the table interaction
+        let mut table_actions = TableActions::default();
 
-        // --- Define Table Columns with Widths ---
         // Give the table a unique ID for egui's state persistence
         let table_id = egui::Id::new("manage_models_table");
-        // Use id_salt instead of id_source
-        let mut builder = TableBuilder::new(ui).id_salt(table_id);
-
-        for col_state in &visible_columns { // Iterate through visible columns directly
-            let initial_width = col_state.width.unwrap_or(default_column_width);
-            // Column doesn't take an ID source/salt directly.
-            // The TableBuilder uses the table's salt and column index for state.
-            builder = builder.column(Column::initial(initial_width).resizable(true));
-        }
-        builder = builder.column(Column::exact(delete_button_width));
-
-        // --- Build the Table Header and Body ---
-        // Capture the response from the table builder
-        let _table_response = builder
-            .striped(true)
-            .resizable(true)
-            .header(20.0, |mut header| {
-                // Iterate through VISIBLE columns to draw headers
-                for col_state in &visible_columns {
-                    let column_enum = &col_state.column; // Get the enum variant
-                    header.col(|ui| {
-                        ui.horizontal_centered(|ui| {
-                            let response = ui.add_enabled(!is_busy, Button::new(RichText::new(column_enum.display_name()).strong()))
-                                .on_hover_text(format!("Sort by {}", column_enum.display_name()));
-
-                            // Only update state here, rebuild/repaint handled in mod.rs
-                            if response.clicked() {
-                                info!("Header clicked for column: {:?}", column_enum); // Log click
-                                if app.model_sort_state.column == *column_enum {
-                                    app.model_sort_state.direction = match app.model_sort_state.direction {
-                                        SortDirection::Ascending => SortDirection::Descending,
-                                        SortDirection::Descending => SortDirection::Ascending,
-                                    };
-                                } else {
-                                    app.model_sort_state.column = column_enum.clone();
-                                    app.model_sort_state.direction = SortDirection::Ascending;
-                                }
-                                info!("Sort state changed to: {:?}, {:?}", app.model_sort_state.column, app.model_sort_state.direction);
-                            }
 
-                            response.context_menu(|ui| {
-                                let can_hide = num_visible_data_columns > 1;
-                                if ui.add_enabled(can_hide, Button::new("Hide column")).clicked() {
-                                    // Store the column to hide, don't modify state here
-                                    column_to_hide = Some(column_enum.clone());
-                                    ui.close_menu();
-                                }
-                                if ui.button("Select columns...").clicked() {
-                                    app.show_select_columns_window = true;
-                                    // Clone current state into pending state if window opens
-                                    if app.pending_column_states.is_none() {
-                                        app.pending_column_states = Some(app.model_column_states.clone());
-                                        info!("'Select columns...' clicked, initializing pending state."); // Debug log
-                                    }
-                                    ui.close_menu();
-                                }
-                            });
+        // Scroll to the model chosen in the quick picker (one-shot)
+        let scroll_to_row = app
+            .scroll_to_model
+            .take()
+            .and_then(|model_name| app.manage_view_cache.iter().position(|m| m.name == model_name));
 
-                            if app.model_sort_state.column == *column_enum {
-                                // --- Reverted sort indicators ---
-                                ui.label(match app.model_sort_state.direction {
-                                    SortDirection::Ascending => " ^",
-                                    SortDirection::Descending => " v",
-                                });
-                            }
-                        });
-                    });
-                }
-                header.col(|ui| { ui.label(""); }); // Empty header for delete column
-            })
-            .body(|body| {
-                // Cache should be clean here because we rebuilt it above if it was dirty
-                let models_to_display = &app.manage_view_cache; // Borrow the clean cache
-
-                body.rows(row_height, models_to_display.len(), |mut row| {
-                    let row_index = row.index();
-                    let model = &models_to_display[row_index];
-
-                    // Iterate through VISIBLE column states
-                    for col_state in &visible_columns { // Use the immutable borrow here
-                        let column_enum = &col_state.column; // Get the enum variant
-                        row.col(|ui| {
-                            let text = match column_enum {
-                                ModelColumn::Name => model.name.clone(),
-                                ModelColumn::Size => model.size_human.clone(),
-                                ModelColumn::Modified => model.modified_local.clone().unwrap_or_else(|| "N/A".to_string()),
-                                ModelColumn::Digest => model.digest.chars().take(12).collect::<String>() + "...",
-                                ModelColumn::Format => model.details.format.clone().unwrap_or_else(|| "-".to_string()),
-                                ModelColumn::Family => model.details.family.clone().unwrap_or_else(|| "-".to_string()),
-                                ModelColumn::Families => model.details.families.as_ref().map(|v| v.join(", ")).unwrap_or_else(|| "-".to_string()),
-                                ModelColumn::ParameterSize => model.details.parameter_size.clone().unwrap_or_else(|| "-".to_string()),
-                                ModelColumn::QuantizationLevel => model.details.quantization_level.clone().unwrap_or_else(|| "-".to_string()),
-                            };
-
-                            if *column_enum == ModelColumn::Digest {
-                                ui.label(text).on_hover_text(&model.digest);
-                            } else {
-                                ui.label(text);
-                            }
-                        });
-                    }
-                    // Cell for the delete button
-                    row.col(|ui| {
-                        if ui.add_enabled(!is_busy, Button::new("🗑").small())
-                           .on_hover_text("Delete Model")
-                           .clicked()
-                        {
-                            app.model_to_delete = Some(model.name.clone());
-                            info!("User initiated delete for model '{}'. Showing confirmation.", model.name);
-                        }
+        // Optionally keep the Name column in place while the other columns scroll horizontally.
+        // egui_extras tables can't freeze columns, so this draws two tables side by side and
+        // keeps their vertical scroll positions in sync.
+        let freeze_name = app.settings.freeze_name_column
+            && num_visible_data_columns > 1
+            && visible_columns.iter().any(|cs| cs.column == ModelColumn::Name);
+        let column_table_id = |column: &ModelColumn| {
+            if !freeze_name {
+                table_id
+            } else if *column == ModelColumn::Name {
+                table_id.with("frozen")
+            } else {
+                table_id.with("scrolling")
+            }
+        };
+
+        if freeze_name {
+            let (frozen_columns, scrolling_columns): (Vec<ColumnState>, Vec<ColumnState>) = visible_columns
+                .iter()
+                .cloned()
+                .partition(|cs| cs.column == ModelColumn::Name);
+            let scroll_offset = app.frozen_table_scroll_offset;
+            let mut new_offsets = (scroll_offset, scroll_offset);
+            ui.horizontal_top(|ui| {
+                new_offsets.0 = draw_models_table(
+                    app,
+                    ui,
+                    TableLayout {
+                        id: column_table_id(&ModelColumn::Name),
+                        columns: frozen_columns,
+                        with_selection: true,
+                        with_actions: false,
+                        row_heights: wrapped_row_heights.clone(),
+                        scroll_offset: Some(scroll_offset),
+                        scroll_to_row,
+                    },
+                    is_busy,
+                    &mut table_actions,
+                );
+                ScrollArea::horizontal()
+                    .id_salt("manage_models_hscroll")
+                    .auto_shrink([false, !app.logs_collapsed])
+                    .show(ui, |ui| {
+                        new_offsets.1 = draw_models_table(
+                            app,
+                            ui,
+                            TableLayout {
+                                id: table_id.with("scrolling"),
+                                columns: scrolling_columns,
+                                with_selection: false,
+                                with_actions: true,
+                                row_heights: wrapped_row_heights,
+                                scroll_offset: Some(scroll_offset),
+                                scroll_to_row,
+                            },
+                            is_busy,
+                            &mut table_actions,
+                        );
                     });
-                });
-            }); // End TableBuilder
+            });
+            // Follow whichever half was scrolled this frame
+            let new_offset = if new_offsets.0 != scroll_offset { new_offsets.0 } else { new_offsets.1 };
+            if new_offset != scroll_offset {
+                app.frozen_table_scroll_offset = new_offset;
+                ui.ctx().request_repaint(); // Move the other half on the next frame
+            }
+        } else {
+            draw_models_table(
+                app,
+                ui,
+                TableLayout {
+                    id: table_id,
+                    columns: visible_columns.clone(),
+                    with_selection: true,
+                    with_actions: true,
+                    row_heights: wrapped_row_heights,
+                    scroll_offset: None,
+                    scroll_to_row,
+                },
+                is_busy,
+                &mut table_actions,
+            );
+        }
+
+        if let Some(model_name) = table_actions.toggled_model.take() {
+            if !app.selected_models.remove(&model_name) {
+                app.selected_models.insert(model_name);
+            }
+        }
+        if let Some(action) = table_actions.row_action {
+            handle_row_action(app, action);
+        }
+        app.prefetch_model_details(&table_actions.visible_models);
 
         // --- Width Capture Logic ---
         for col_state in app.model_column_states.iter_mut() {
              // Generate the ID egui uses for this column's state within the table
-             let column_id = table_id.with(&col_state.column);
+             let column_id = column_table_id(&col_state.column).with(&col_state.column);
              debug!("Checking width for column {:?} using ID: {:?}", col_state.column, column_id);
              // Try to get the width stored by egui for this specific column ID
              if let Some(new_width) = ui.memory(|m| m.data.get_temp::<f32>(column_id)) {
@@ -267,7 +409,7 @@ pub fn draw_manage_models_view(
 
         // --- Apply Deferred State Changes (Column Hiding) ---
         // Apply column hiding *after* the table UI is finished drawing and widths captured
-        if let Some(col_to_hide) = column_to_hide {
+        if let Some(col_to_hide) = table_actions.column_to_hide {
             if let Some(state) = app.model_column_states.iter_mut().find(|cs| cs.column == col_to_hide) {
                 state.visible = false;
                 info!("Hiding column: {:?}", col_to_hide);
@@ -276,3 +418,881 @@ pub fn draw_manage_models_view(
         }
     }
 }
+
+// Fixed widths of the models table's selection and actions columns, and the initial width of data columns.
+const SELECT_COLUMN_WIDTH: f32 = 24.0;
+const ACTIONS_COLUMN_WIDTH: f32 = 85.0;
+const DEFAULT_COLUMN_WIDTH: f32 = 120.0;
+
+// Which part of the models table one TableBuilder draws. With the Name column frozen,
+// the table is split into two side by side (Name, and everything else).
+struct TableLayout {
+    id: egui::Id,
+    columns: Vec<ColumnState>,
+    with_selection: bool,
+    with_actions: bool,
+    row_heights: Option<Vec<f32>>, // Per-row heights in wrap mode, uniform rows otherwise
+    scroll_offset: Option<f32>,    // Vertical offset to keep split tables in sync
+    scroll_to_row: Option<usize>,
+}
+
+// Interactions with the models table, applied after it was drawn.
+#[derive(Default)]
+struct TableActions {
+    column_to_hide: Option<ModelColumn>,
+    row_action: Option<RowAction>,
+    visible_models: Vec<String>, // Rows drawn this frame (the table body only draws visible rows)
+    toggled_model: Option<String>, // Row clicked this frame (toggles its selection)
+}
+
+// Draws (part of) the flat models table with sortable headers, per-column context menus
+// and row actions.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ui - Mutable reference to the egui UI context for drawing.
+// * layout - The columns and options of this table (or table half).
+// * is_busy - Whether an operation is running (disables sorting and row actions).
+// * actions - Collects the interactions to apply after drawing.
+//
+// # Returns
+//
+// * The vertical scroll offset of the table body after this frame.
+fn draw_models_table(
+    app: &mut OllamaPullerApp,
+    ui: &mut Ui,
+    layout: TableLayout,
+    is_busy: bool,
+    actions: &mut TableActions,
+) -> f32 {
+    // Calculate row height *before* the TableBuilder borrows ui mutably
+    let row_height = ui.text_style_height(&egui::TextStyle::Body);
+    let mut builder = TableBuilder::new(ui).id_salt(layout.id);
+    if layout.with_selection {
+        builder = builder.column(Column::exact(SELECT_COLUMN_WIDTH)); // Selection checkboxes
+    }
+
+    for col_state in &layout.columns { // Iterate through visible columns directly
+        let initial_width = col_state.width.unwrap_or(DEFAULT_COLUMN_WIDTH);
+        // Column doesn't take an ID source/salt directly.
+        // The TableBuilder uses the table's salt and column index for state.
+        builder = builder.column(Column::initial(initial_width).resizable(true));
+    }
+    if layout.with_actions {
+        builder = builder.column(Column::exact(ACTIONS_COLUMN_WIDTH));
+    }
+
+    if let Some(row_index) = layout.scroll_to_row {
+        builder = builder.scroll_to_row(row_index, Some(egui::Align::Center));
+    }
+    if let Some(offset) = layout.scroll_offset {
+        builder = builder.vertical_scroll_offset(offset);
+    }
+
+    // --- Build the Table Header and Body ---
+    // Capture the output of the table builder (for its scroll offset)
+    let gridlines = app.settings.table_gridlines;
+    let wrap_text = app.settings.table_wrap_text;
+    let header_color = app.settings.table_header_color.map(|[r, g, b]| Color32::from_rgb(r, g, b));
+    let num_visible_data_columns = app.model_column_states.iter().filter(|cs| cs.visible).count();
+    // With the logs expanded, a short list takes only its rows' height and the log panel gets the rest
+    let shrink_to_rows = !app.logs_collapsed;
+    let table_output = builder
+        .striped(app.settings.table_striped)
+        .resizable(true)
+        .sense(egui::Sense::click()) // Clicking a row toggles its selection
+        // Keep the header sticky: only the body lives in the table's vertical ScrollArea,
+        // which fills the remaining panel height instead of growing the panel.
+        .vscroll(true)
+        .auto_shrink([!layout.with_actions, shrink_to_rows]) // The frozen half is only as wide as its columns
+        .min_scrolled_height(0.0)
+        .header(20.0, |mut header| {
+            if layout.with_selection {
+                header.col(|ui| {
+                    paint_header_background(ui, header_color);
+                    ui.label("");
+                }); // Empty header for selection column
+            }
+            // Iterate through VISIBLE columns to draw headers
+            for col_state in &layout.columns {
+                let column_enum = &col_state.column; // Get the enum variant
+                header.col(|ui| {
+                    paint_header_background(ui, header_color);
+                    if gridlines {
+                        paint_column_gridline(ui);
+                    }
+                    ui.horizontal_centered(|ui| {
+                        let response = ui.add_enabled(!is_busy, Button::new(RichText::new(column_enum.display_name()).strong()))
+                            .on_hover_text(format!("Sort by {}", column_enum.display_name()));
+
+                        // Only update state here, rebuild/repaint handled in mod.rs
+                        if response.clicked() {
+                            info!("Header clicked for column: {:?}", column_enum); // Log click
+                            if app.model_sort_state.column == *column_enum {
+                                app.model_sort_state.direction = match app.model_sort_state.direction {
+                                    SortDirection::Ascending => SortDirection::Descending,
+                                    SortDirection::Descending => SortDirection::Ascending,
+                                };
+                            } else {
+                                app.model_sort_state.column = column_enum.clone();
+                                app.model_sort_state.direction = SortDirection::Ascending;
+                            }
+                            info!("Sort state changed to: {:?}, {:?}", app.model_sort_state.column, app.model_sort_state.direction);
+                        }
+
+                        response.context_menu(|ui| {
+                            let can_hide = num_visible_data_columns > 1;
+                            if ui.add_enabled(can_hide, Button::new("Hide column")).clicked() {
+                                // Store the column to hide, don't modify state here
+                                actions.column_to_hide = Some(column_enum.clone());
+                                ui.close_menu();
+                            }
+                            if ui.button("Select columns...").clicked() {
+                                app.show_select_columns_window = true;
+                                // Clone current state into pending state if window opens
+                                if app.pending_column_states.is_none() {
+                                    app.pending_column_states = Some(app.model_column_states.clone());
+                                    info!("'Select columns...' clicked, initializing pending state."); // Debug log
+                                }
+                                ui.close_menu();
+                            }
+                        });
+
+                        if app.model_sort_state.column == *column_enum {
+                            // --- Reverted sort indicators ---
+                            ui.label(match app.model_sort_state.direction {
+                                SortDirection::Ascending => " ^",
+                                SortDirection::Descending => " v",
+                            });
+                        }
+                    });
+                });
+            }
+            if layout.with_actions {
+                header.col(|ui| {
+                    paint_header_background(ui, header_color);
+                    ui.label("");
+                }); // Empty header for actions column
+            }
+        })
+        .body(|body| {
+            // Cache should be clean here because we rebuilt it above if it was dirty
+            let models_to_display = &app.manage_view_cache; // Borrow the clean cache
+
+            let draw_row = |mut row: TableRow| {
+                let row_index = row.index();
+                let model = &models_to_display[row_index];
+                // Selection is kept by name, so it follows the model when the list is sorted, filtered or refreshed
+                row.set_selected(
+                    app.highlighted_model.as_ref() == Some(&model.name) || app.selected_models.contains(&model.name),
+                );
+                actions.visible_models.push(model.name.clone());
+
+                // Cell for the selection checkbox (used by Compare)
+                if layout.with_selection {
+                    row.col(|ui| {
+                        draw_selection_checkbox(&mut app.selected_models, ui, &model.name);
+                    });
+                }
+                // Iterate through VISIBLE column states
+                for col_state in &layout.columns {
+                    let column_enum = &col_state.column; // Get the enum variant
+                    row.col(|ui| {
+                        if gridlines {
+                            paint_column_gridline(ui);
+                        }
+                        let text = cell_text(model, column_enum);
+                        let label = if wrap_text {
+                            Label::new(text).wrap_mode(TextWrapMode::Wrap)
+                        } else {
+                            Label::new(text)
+                        };
+
+                        if *column_enum == ModelColumn::Name {
+                            draw_name_cell(ui, model, label);
+                        } else if *column_enum == ModelColumn::Modified && model.modified_dt.is_none() {
+                            draw_unparsed_modified_cell(ui, model, label);
+                        } else if *column_enum == ModelColumn::Digest {
+                            ui.add(label).on_hover_text(&model.digest);
+                        } else if *column_enum == ModelColumn::Size {
+                            ui.add(label).on_hover_text(format!("{} bytes", format_bytes_grouped(model.size)));
+                        } else if matches!(column_enum, ModelColumn::Lineage | ModelColumn::ContextLength)
+                            && model.lineage.is_none()
+                        {
+                            ui.add(label).on_hover_text("Needs the model's /api/show details: enable \"Prefetch for visible rows\" in Settings.");
+                        } else if *column_enum == ModelColumn::Reclaimable {
+                            ui.add(label).on_hover_text(match model.reclaimable_size {
+                                Some(size) => format!(
+                                    "Deleting only this model frees {} bytes; the rest of its reported {} is shared with other models.",
+                                    format_bytes_grouped(size),
+                                    model.size_human
+                                ),
+                                None => "Not computed: use \"Compute Reclaimable\" under Disk usage (needs the local model store).".to_string(),
+                            });
+                        } else {
+                            ui.add(label);
+                        }
+                    });
+                }
+                // Cell for the load/delete buttons
+                if layout.with_actions {
+                    row.col(|ui| {
+                        if let Some(action) = draw_row_actions(ui, &model.name, is_busy) {
+                            actions.row_action = Some(action);
+                        }
+                    });
+                }
+                if row.response().clicked() {
+                    actions.toggled_model = Some(model.name.clone());
+                }
+            };
+            match layout.row_heights {
+                Some(heights) => body.heterogeneous_rows(heights.into_iter(), draw_row),
+                None => body.rows(row_height, models_to_display.len(), draw_row),
+            }
+        }); // End TableBuilder
+    table_output.state.offset.y
+}
+
+// Draws the quick stats shown when hovering the "Manage Models" tab.
+// Only uses already cached data (no requests), so it is cheap to call on every hover frame.
+//
+// # Arguments
+//
+// * app - Reference to the main application state (OllamaPullerApp).
+// * ui - Mutable reference to the egui UI context of the tooltip.
+pub fn draw_manage_stats_tooltip(app: &OllamaPullerApp, ui: &mut Ui) {
+    let (model_count, unknown_size_count, total_size) = {
+        let models = app.listed_models.lock().unwrap();
+        (
+            models.len(),
+            models.iter().filter(|m| m.size == 0).count(),
+            models.iter().map(|m| m.size).sum::<u64>(),
+        )
+    };
+    let last_refresh = app
+        .last_model_list_at
+        .map(|dt| {
+            let local_tz = Tz::from_str(&app.settings.tz).unwrap_or(Tz::UTC);
+            dt.with_timezone(&local_tz).format("%Y-%m-%d %H:%M:%S").to_string()
+        })
+        .unwrap_or_else(|| "never".to_string());
+
+    Grid::new("manage_stats_tooltip_grid")
+        .num_columns(2)
+        .spacing([10.0, 2.0])
+        .show(ui, |ui| {
+            ui.label("Models:");
+            ui.label(model_count.to_string());
+            ui.end_row();
+            ui.label("Total size:");
+            if unknown_size_count > 0 {
+                ui.label(format!("{} ({} without a size)", format_size(total_size), unknown_size_count));
+            } else {
+                ui.label(format_size(total_size));
+            }
+            ui.end_row();
+            ui.label("Loaded:");
+            ui.label(app.running_models.len().to_string());
+            ui.end_row();
+            ui.label("Last refresh:");
+            ui.label(last_refresh);
+            ui.end_row();
+        });
+}
+
+// Fills a header cell with the custom header color, if one is set (see Settings).
+fn paint_header_background(ui: &mut Ui, color: Option<Color32>) {
+    if let Some(color) = color {
+        // Also cover the spacing between cells, for a continuous header bar
+        let rect = ui.max_rect().expand2(egui::vec2(ui.spacing().item_spacing.x * 0.5, 0.0));
+        ui.painter().rect_filled(rect, 0.0, color);
+    }
+}
+
+// Draws the Name cell: the name, preceded by a subtle icon telling `:latest` models (which change when
+// pulled again) from models pinned to a specific tag and from untagged names.
+fn draw_name_cell(ui: &mut Ui, model: &OllamaModel, label: Label) {
+    let (icon, hover_text) = match model_tag(&model.name) {
+        Some("latest") => ("🔄", "Tagged ':latest': pulling it again may update it to a newer version."),
+        Some(_) => ("📌", "Pinned to a specific tag: this version doesn't change when pulled again."),
+        None => ("•", "No tag: Ollama treats it as ':latest', so it may change when pulled again."),
+    };
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 4.0;
+        ui.label(RichText::new(icon).small().weak()).on_hover_text(hover_text);
+        ui.add(label);
+        if model.update_available {
+            ui.label(RichText::new("⬆").small().color(ui.visuals().hyperlink_color))
+                .on_hover_text("A newer version of this tag is on registry.ollama.ai. Pull it again to update.");
+        }
+    });
+}
+
+// Where the view shows an error status: inline when there is no list to keep, as a notice over the
+// last good list, or nowhere once that notice was dismissed.
+#[derive(Debug, PartialEq)]
+enum ErrorNotice {
+    Hidden,
+    Inline,
+    Toast,
+}
+
+fn error_notice(status: &AppStatus, list_is_empty: bool, toast_dismissed: bool) -> ErrorNotice {
+    match status {
+        AppStatus::Error(_) if list_is_empty => ErrorNotice::Inline,
+        AppStatus::Error(_) if !toast_dismissed => ErrorNotice::Toast,
+        _ => ErrorNotice::Hidden,
+    }
+}
+
+// Returns whether the error notice stays dismissed after a frame whose status went from
+// `status_was_error` to `status_is_error`: a new error (a transition into the error state) shows it again.
+pub(crate) fn error_toast_dismissed_after(toast_dismissed: bool, status_was_error: bool, status_is_error: bool) -> bool {
+    toast_dismissed && (status_was_error || !status_is_error)
+}
+
+// Draws a dismissible error notice in the bottom right corner of the window, used instead of the
+// inline error text when the table still shows the last successfully fetched list.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (&egui::Context).
+// * error - The error string from AppStatus::Error.
+fn draw_error_toast(app: &mut OllamaPullerApp, ctx: &egui::Context, error: &str) {
+    let last_refresh = app.last_model_list_at.map(|dt| {
+        let local_tz = Tz::from_str(&app.settings.tz).unwrap_or(Tz::UTC);
+        dt.with_timezone(&local_tz).format("%H:%M:%S").to_string()
+    });
+    egui::Area::new(egui::Id::new("manage_error_toast"))
+        .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-12.0, -48.0)) // Above the log panel header
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_max_width(360.0);
+                ui.horizontal(|ui| {
+                    ui.colored_label(ui.visuals().error_fg_color, "⚠ The last operation failed.");
+                    if ui.small_button("✕").on_hover_text("Dismiss").clicked() {
+                        app.error_toast_dismissed = true;
+                    }
+                });
+                match &last_refresh {
+                    Some(time) => ui.label(format!("The list shows the last good data (refreshed at {}).", time)),
+                    None => ui.label("The list shows the last good data."),
+                };
+                draw_error_details(ui, "manage_error_toast_details", error);
+            });
+        });
+}
+
+// Draws a Modified cell whose timestamp could not be parsed: a subtle warning icon with the raw value
+// the server sent on hover. Such models sort after all others by modification date.
+fn draw_unparsed_modified_cell(ui: &mut Ui, model: &OllamaModel, label: Label) {
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 4.0;
+        let hover_text = if model.listed_via_cli {
+            format!(
+                "Listed via `ollama list`, which only reports '{}' (the API was unreachable). Sorted last by Modified.",
+                model.modified_at
+            )
+        } else {
+            format!(
+                "The server sent '{}', which is not an RFC 3339 timestamp. Sorted last by Modified.",
+                model.modified_at
+            )
+        };
+        ui.label(RichText::new("⚠").small().color(ui.visuals().warn_fg_color))
+            .on_hover_text(hover_text);
+        ui.add(label);
+    });
+}
+
+// Paints a vertical gridline along the left edge of a table cell (optional, see Settings).
+fn paint_column_gridline(ui: &mut Ui) {
+    let rect = ui.max_rect();
+    ui.painter().vline(
+        rect.left() - ui.spacing().item_spacing.x * 0.5,
+        rect.y_range(),
+        ui.visuals().widgets.noninteractive.bg_stroke,
+    );
+}
+
+// Returns the display text for a single cell of the models table.
+fn cell_text(model: &OllamaModel, column: &ModelColumn) -> String {
+    match column {
+        ModelColumn::Name => model.name.clone(),
+        ModelColumn::Size => model.size_human.clone(),
+        ModelColumn::Modified => model.modified_local.clone().unwrap_or_else(|| "N/A".to_string()),
+        ModelColumn::Digest => model.digest_display.clone(),
+        ModelColumn::Format => model.details.format.clone().unwrap_or_else(|| "-".to_string()),
+        ModelColumn::Family => model.details.family.clone().unwrap_or_else(|| "-".to_string()),
+        ModelColumn::Families => match model.details.families_list() {
+            [] => "-".to_string(),
+            families => families.join(", "),
+        },
+        ModelColumn::ParameterSize => model.details.parameter_size.clone().unwrap_or_else(|| "-".to_string()),
+        ModelColumn::QuantizationLevel => model.details.quantization_level.clone().unwrap_or_else(|| "-".to_string()),
+        ModelColumn::LastUsed => model.last_used_local.clone().unwrap_or_else(|| "never".to_string()),
+        ModelColumn::Lineage => model.lineage.clone().unwrap_or_else(|| "-".to_string()),
+        ModelColumn::ContextLength => model.context_length.map_or_else(|| "-".to_string(), |tokens| tokens.to_string()),
+        ModelColumn::Reclaimable => model.reclaimable_size.map_or_else(|| "-".to_string(), format_size),
+    }
+}
+
+// Formats the models as a Markdown table with the given columns (pipes in values are escaped).
+fn models_as_markdown(models: &[OllamaModel], columns: &[ModelColumn]) -> String {
+    let escape = |text: &str| text.replace('|', "\\|");
+    let header: Vec<String> = columns.iter().map(|c| escape(c.display_name())).collect();
+    let mut lines = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("|{}|", vec![" --- "; columns.len()].join("|")),
+    ];
+    for model in models {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| escape(&cell_text(model, column)))
+            .collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+    lines.join("\n") + "\n"
+}
+
+// Number of models shown in the disk usage overview.
+const SIZE_OVERVIEW_TOP_N: usize = 10;
+
+// Draws a collapsible bar chart of the largest models, to quickly spot what to prune, and the button
+// computing how much deleting each model would really free (reported sizes count shared layers per model).
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ui - Mutable reference to the egui UI context for drawing.
+fn draw_size_overview(app: &mut OllamaPullerApp, ui: &mut Ui) {
+    let mut compute_clicked = false;
+    let sizes: Vec<u64> = app.manage_view_cache.iter().map(|m| m.size).collect();
+    let total_size: u64 = sizes.iter().sum();
+    let mut largest: Vec<&OllamaModel> = app.manage_view_cache.iter().filter(|m| m.size > 0).collect();
+    largest.sort_unstable_by_key(|m| std::cmp::Reverse(m.size));
+    largest.truncate(SIZE_OVERVIEW_TOP_N);
+    let max_size = largest.first().map(|m| m.size).unwrap_or(0);
+
+    CollapsingHeader::new(disk_usage_summary(&sizes, app.model_store_space.as_ref().map(|(_, free)| *free)))
+    .id_salt("size_overview")
+    .default_open(false)
+    .show(ui, |ui| {
+        if let Some((dir, free)) = &app.model_store_space {
+            ui.label(format!("Model store: {} ({} free on this volume)", dir.display(), format_size(*free)));
+        }
+        ui.horizontal(|ui| {
+            compute_clicked = ui
+                .add_enabled(
+                    app.model_store_space.is_some() && !app.reclaimable_sizes_running,
+                    egui::Button::new("🧮 Compute Reclaimable"),
+                )
+                .on_hover_text("Read the local manifests to find each model's layers no other model shares (shown in the \"Reclaimable\" column)")
+                .on_disabled_hover_text("Needs the local model store (Ollama running on this machine)")
+                .clicked();
+            if app.reclaimable_sizes_running {
+                ui.spinner();
+            } else if let Some(sizes) = &app.reclaimable_sizes {
+                ui.label(format!(
+                    "{} in total is not shared between models",
+                    format_size(sizes.values().sum())
+                ));
+            }
+        });
+        ui.label(format!("Largest {} models:", largest.len()));
+        Grid::new("size_overview_grid")
+            .num_columns(3)
+            .spacing([10.0, 2.0])
+            .show(ui, |ui| {
+                let bar_max_width = 200.0;
+                let bar_height = ui.text_style_height(&egui::TextStyle::Body) * 0.8;
+                for model in &largest {
+                    ui.label(&model.name);
+                    let fraction = if max_size > 0 {
+                        model.size as f32 / max_size as f32
+                    } else {
+                        0.0
+                    };
+                    let (rect, response) =
+                        ui.allocate_exact_size(egui::vec2(bar_max_width, bar_height), Sense::hover());
+                    let mut bar_rect = rect;
+                    bar_rect.set_width((bar_max_width * fraction).max(1.0));
+                    ui.painter().rect_filled(bar_rect, 2.0, ui.visuals().selection.bg_fill);
+                    if total_size > 0 {
+                        response.on_hover_text(format!(
+                            "{:.1}% of total",
+                            model.size as f64 / total_size as f64 * 100.0
+                        ));
+                    }
+                    ui.label(&model.size_human);
+                    ui.end_row();
+                }
+            });
+    });
+
+    if compute_clicked {
+        app.compute_reclaimable_sizes();
+    }
+}
+
+// Returns the title of the disk usage overview for the given model sizes and the free space of the
+// model store (if known). Models reporting size 0 (some custom/blob models) are counted separately,
+// not as empty models.
+fn disk_usage_summary(sizes: &[u64], free_space: Option<u64>) -> String {
+    let total_size: u64 = sizes.iter().sum();
+    let unknown_size_count = sizes.iter().filter(|size| **size == 0).count();
+    let unknown_size_text = if unknown_size_count > 0 {
+        format!(" (+{} without a size)", unknown_size_count)
+    } else {
+        String::new()
+    };
+    let free_space_text = free_space.map_or_else(String::new, |free| format!(", {} free", format_size(free)));
+    format!(
+        "Disk usage: {} in {} models{}{}",
+        format_size(total_size),
+        sizes.len() - unknown_size_count,
+        unknown_size_text,
+        free_space_text
+    )
+}
+
+// Draws the "Go to model" picker: a searchable combo box of all model names.
+// Choosing a model highlights its row and scrolls the table to it (or expands its group).
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ui - Mutable reference to the egui UI context for drawing.
+fn draw_model_picker(app: &mut OllamaPullerApp, ui: &mut Ui) {
+    let mut picked: Option<String> = None;
+    ui.horizontal(|ui| {
+        ui.label("Go to model:");
+        ComboBox::from_id_salt("model_picker")
+            .selected_text(app.highlighted_model.clone().unwrap_or_default())
+            .width(250.0)
+            .show_ui(ui, |ui| {
+                ui.add(
+                    TextEdit::singleline(&mut app.model_picker_filter)
+                        .hint_text("Search...")
+                        .desired_width(f32::INFINITY),
+                )
+                .request_focus();
+                let filter = app.model_picker_filter.to_lowercase();
+                // The cache is the sorted list, so names appear in table order
+                for model in app
+                    .manage_view_cache
+                    .iter()
+                    .filter(|m| m.name.to_lowercase().contains(&filter))
+                {
+                    let is_current = app.highlighted_model.as_ref() == Some(&model.name);
+                    if ui.selectable_label(is_current, &model.name).clicked() {
+                        picked = Some(model.name.clone());
+                    }
+                }
+            });
+        if app.highlighted_model.is_some() && ui.small_button("✖").on_hover_text("Clear").clicked() {
+            app.highlighted_model = None;
+        }
+    });
+
+    if let Some(model_name) = picked {
+        debug!("Model picker: going to '{}'.", model_name);
+        // Make sure the model is visible in the grouped view as well
+        app.model_group_open.insert(base_model_name(&model_name).to_string(), true);
+        app.scroll_to_model = Some(model_name.clone());
+        app.highlighted_model = Some(model_name);
+        app.model_picker_filter.clear();
+    }
+}
+
+// Draws the checkbox that adds/removes a model from the selection set.
+fn draw_selection_checkbox(selected_models: &mut HashSet<String>, ui: &mut Ui, model_name: &str) {
+    let mut is_selected = selected_models.contains(model_name);
+    if ui.checkbox(&mut is_selected, "").changed() {
+        if is_selected {
+            selected_models.insert(model_name.to_string());
+        } else {
+            selected_models.remove(model_name);
+        }
+    }
+}
+
+// Draws the cached models grouped under their base name (e.g. 'llama3' for 'llama3:8b').
+// A base name with several tags becomes a single expandable entry showing the tag count and
+// total size; expanding it lists each tag. Models with a single tag are shown as plain rows.
+// Groups keep the order of the current sort. Each group's open state lives in app.model_group_open
+// (keyed by base name), so it survives cache rebuilds and can be flipped for all groups at once.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ui - Mutable reference to the egui UI context for drawing.
+// * is_busy - Whether an operation is running (disables the delete buttons).
+fn draw_grouped_models(app: &mut OllamaPullerApp, ui: &mut Ui, is_busy: bool) {
+    // Collect indices into the sorted cache per base name, in order of first appearance
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for (index, model) in app.manage_view_cache.iter().enumerate() {
+        let base = base_model_name(&model.name);
+        match groups.iter_mut().find(|(name, _)| name == base) {
+            Some((_, indices)) => indices.push(index),
+            None => groups.push((base.to_string(), vec![index])),
+        }
+    }
+
+    let visible_columns: Vec<ModelColumn> = app
+        .model_column_states
+        .iter()
+        .filter(|cs| cs.visible)
+        .map(|cs| cs.column.clone())
+        .collect();
+    let mut row_action: Option<RowAction> = None;
+
+    // Collapse / expand every multi-tag group at once
+    ui.horizontal(|ui| {
+        let mut set_all_open = None;
+        if ui.button("Expand all").clicked() {
+            set_all_open = Some(true);
+        }
+        if ui.button("Collapse all").clicked() {
+            set_all_open = Some(false);
+        }
+        if let Some(open) = set_all_open {
+            debug!("Setting all model groups open: {}", open);
+            for (base, indices) in &groups {
+                if indices.len() > 1 {
+                    app.model_group_open.insert(base.clone(), open);
+                }
+            }
+        }
+    });
+
+    ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for (base, indices) in &groups {
+                let models: Vec<&OllamaModel> =
+                    indices.iter().map(|&i| &app.manage_view_cache[i]).collect();
+
+                if models.len() == 1 {
+                    // Nothing to condense, show the single tag as a plain row
+                    draw_model_rows(
+                        ui,
+                        &models,
+                        &visible_columns,
+                        false,
+                        &mut app.selected_models,
+                        is_busy,
+                        &mut row_action,
+                    );
+                    continue;
+                }
+
+                let total_size: u64 = models.iter().map(|m| m.size).sum();
+                let is_open = app.model_group_open.get(base).copied().unwrap_or(false);
+                let group_response = CollapsingHeader::new(format!(
+                    "{} ({} tags, {})",
+                    base,
+                    models.len(),
+                    format_size(total_size)
+                ))
+                .id_salt(("model_group", base))
+                .open(Some(is_open))
+                .show(ui, |ui| {
+                    draw_model_rows(
+                        ui,
+                        &models,
+                        &visible_columns,
+                        true,
+                        &mut app.selected_models,
+                        is_busy,
+                        &mut row_action,
+                    );
+                });
+                if group_response.header_response.clicked() {
+                    app.model_group_open.insert(base.clone(), !is_open);
+                }
+                group_response
+                    .header_response
+                    .on_hover_text("Right-click to copy the pull commands for all tags")
+                    .context_menu(|ui| {
+                        if ui.button("📋 Copy pull commands for all tags").clicked() {
+                            ui.ctx().copy_text(pull_commands(&models));
+                            info!("Copied pull commands for {} tags of '{}'.", models.len(), base);
+                            ui.close_menu();
+                        }
+                    });
+            }
+        });
+
+    if let Some(action) = row_action {
+        handle_row_action(app, action);
+    }
+}
+
+// Builds one `ollama pull <name>` line per model, e.g. to replicate a set of tags elsewhere.
+fn pull_commands(models: &[&OllamaModel]) -> String {
+    models
+        .iter()
+        .map(|model| format!("ollama pull {}", model.name))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// An action requested from a model row's buttons, applied after drawing.
+enum RowAction {
+    Info(String),
+    Load(String),
+    Delete(String),
+}
+
+// Draws a model row's info, "load into memory" and delete buttons.
+// Returns the requested action, if a button was clicked.
+fn draw_row_actions(ui: &mut Ui, model_name: &str, is_busy: bool) -> Option<RowAction> {
+    let mut action = None;
+    ui.horizontal(|ui| {
+        if ui.add(Button::new("ℹ").small())
+            .on_hover_text("Model info (full digest and details)")
+            .clicked()
+        {
+            action = Some(RowAction::Info(model_name.to_string()));
+        }
+        if ui.add_enabled(!is_busy, Button::new("⚡").small())
+            .on_hover_text("Load into memory")
+            .clicked()
+        {
+            action = Some(RowAction::Load(model_name.to_string()));
+        }
+        if ui.add_enabled(!is_busy, Button::new("🗑").small())
+            .on_hover_text("Delete Model")
+            .clicked()
+        {
+            action = Some(RowAction::Delete(model_name.to_string()));
+        }
+    });
+    action
+}
+
+// Queues a row action; the load/delete itself is started from app/mod.rs.
+fn handle_row_action(app: &mut OllamaPullerApp, action: RowAction) {
+    match action {
+        RowAction::Info(model_name) => {
+            debug!("Showing info for model '{}'.", model_name);
+            app.info_model = Some(model_name);
+        }
+        RowAction::Load(model_name) => {
+            info!("User requested loading model '{}' into memory.", model_name);
+            app.model_to_load = Some(model_name);
+        }
+        RowAction::Delete(model_name) => {
+            info!("User initiated delete for model '{}'. Showing confirmation.", model_name);
+            app.model_to_delete = Some(model_name);
+        }
+    }
+}
+
+// Draws a grid of model rows (selection checkbox, visible columns, load/delete buttons) for the grouped view.
+// `models` must not be empty; the grid is keyed by the first model's name.
+fn draw_model_rows(
+    ui: &mut Ui,
+    models: &[&OllamaModel],
+    visible_columns: &[ModelColumn],
+    show_header: bool,
+    selected_models: &mut HashSet<String>,
+    is_busy: bool,
+    row_action: &mut Option<RowAction>,
+) {
+    Grid::new(("model_group_grid", &models[0].name))
+        .num_columns(visible_columns.len() + 2)
+        .striped(true)
+        .show(ui, |ui| {
+            if show_header {
+                ui.label(""); // Empty header for selection column
+                for column in visible_columns {
+                    ui.label(RichText::new(column.display_name()).strong());
+                }
+                ui.label(""); // Empty header for actions column
+                ui.end_row();
+            }
+
+            for model in models {
+                draw_selection_checkbox(selected_models, ui, &model.name);
+                for column in visible_columns {
+                    let text = cell_text(model, column);
+                    if *column == ModelColumn::Name {
+                        draw_name_cell(ui, model, Label::new(text));
+                    } else if *column == ModelColumn::Modified && model.modified_dt.is_none() {
+                        draw_unparsed_modified_cell(ui, model, Label::new(text));
+                    } else if *column == ModelColumn::Digest {
+                        ui.label(text).on_hover_text(&model.digest);
+                    } else if *column == ModelColumn::Size {
+                        ui.label(text).on_hover_text(format!("{} bytes", format_bytes_grouped(model.size)));
+                    } else {
+                        ui.label(text);
+                    }
+                }
+                if let Some(action) = draw_row_actions(ui, &model.name, is_busy) {
+                    *row_action = Some(action);
+                }
+                ui.end_row();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Replays the per-frame status check in `update` for a sequence of statuses, returning the notice
+    // shown on each frame; `dismiss_on` closes the notice on that frame.
+    fn notices_for(statuses: &[AppStatus], list_is_empty: bool, dismiss_on: Option<usize>) -> Vec<ErrorNotice> {
+        let mut status_was_error = false;
+        let mut toast_dismissed = false;
+        let mut notices = Vec::new();
+        for (frame, status) in statuses.iter().enumerate() {
+            let status_is_error = matches!(status, AppStatus::Error(_));
+            toast_dismissed = error_toast_dismissed_after(toast_dismissed, status_was_error, status_is_error);
+            status_was_error = status_is_error;
+            notices.push(error_notice(status, list_is_empty, toast_dismissed));
+            if dismiss_on == Some(frame) {
+                toast_dismissed = true;
+            }
+        }
+        notices
+    }
+
+    #[test]
+    fn refresh_error_after_success_keeps_the_list_and_shows_a_notice() {
+        let error = || AppStatus::Error("connection refused".to_string());
+        let statuses = [AppStatus::ListingModels, AppStatus::Idle, AppStatus::ListingModels, error(), error()];
+        assert_eq!(
+            notices_for(&statuses, false, None),
+            [ErrorNotice::Hidden, ErrorNotice::Hidden, ErrorNotice::Hidden, ErrorNotice::Toast, ErrorNotice::Toast]
+        );
+        // Without a list to keep, the error is shown inline instead
+        assert_eq!(notices_for(&statuses, true, None)[3], ErrorNotice::Inline);
+    }
+
+    #[test]
+    fn dismissed_notice_returns_only_for_the_next_error() {
+        let error = || AppStatus::Error("connection refused".to_string());
+        let statuses = [AppStatus::Idle, error(), error(), AppStatus::ListingModels, error()];
+        assert_eq!(
+            notices_for(&statuses, false, Some(1)),
+            [ErrorNotice::Hidden, ErrorNotice::Toast, ErrorNotice::Hidden, ErrorNotice::Hidden, ErrorNotice::Toast]
+        );
+    }
+
+    #[test]
+    fn disk_usage_summary_counts_models_without_a_size_separately() {
+        assert_eq!(
+            disk_usage_summary(&[1024, 0, 2048], Some(1024 * 1024)),
+            "Disk usage: 3.00 KiB in 2 models (+1 without a size), 1.00 MiB free"
+        );
+        assert_eq!(disk_usage_summary(&[0, 0], None), "Disk usage: 0 B in 0 models (+2 without a size)");
+        assert_eq!(disk_usage_summary(&[], None), "Disk usage: 0 B in 0 models");
+    }
+}
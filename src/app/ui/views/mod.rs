@@ -6,3 +6,6 @@ pub mod download_view;
 
 /// Contains the UI drawing function for the Manage Models view.
 pub mod manage_models_view;
+
+/// Contains the UI drawing function for the (advanced) Prompt Workbench view.
+pub mod workbench_view;
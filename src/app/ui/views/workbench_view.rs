@@ -0,0 +1,295 @@
+// src/app/ui/views/workbench_view.rs
+// Contains the UI drawing function for the (advanced) Prompt Workbench view: try a system prompt and
+// parameters against a base model via /api/generate, then save them as a new model via /api/create.
+
+use crate::app::{
+    ollama::{self, GenerateEnd, OllamaCreateRequest, OllamaGenerateRequest},
+    state::{AppStatus, UpdateMessage, WorkbenchState},
+    ui::widgets::draw_error_details,
+    utils::validate_model_identifier,
+    OllamaPullerApp,
+};
+use egui::{Button, ComboBox, DragValue, Grid, ScrollArea, TextEdit, Ui};
+use log::{error, info, warn};
+use std::collections::HashMap;
+use std::sync::{atomic::Ordering, Arc};
+
+// --- View Drawing Functions ---
+
+// Draws the content for the "Prompt Workbench" view.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ui - Mutable reference to the egui UI context for drawing.
+// * current_status - Reference to the current application status (AppStatus).
+pub fn draw_workbench_view(app: &mut OllamaPullerApp, ui: &mut Ui, current_status: &AppStatus) {
+    let is_busy = !matches!(
+        current_status,
+        AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+    );
+
+    ui.heading("Prompt Workbench (Advanced)");
+    ui.label("Try a system prompt and parameters on a base model, then save them as a new model.");
+    ui.separator();
+
+    let model_names: Vec<String> = app.listed_models.lock().unwrap().iter().map(|m| m.name.clone()).collect();
+    let workbench = &mut app.workbench;
+
+    Grid::new("workbench_grid")
+        .num_columns(2)
+        .spacing([20.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("Base Model:");
+            ComboBox::from_id_salt("workbench_base_model")
+                .selected_text(if workbench.base_model.is_empty() {
+                    "Select a model..."
+                } else {
+                    workbench.base_model.as_str()
+                })
+                .width(250.0)
+                .show_ui(ui, |ui| {
+                    for name in &model_names {
+                        ui.selectable_value(&mut workbench.base_model, name.clone(), name);
+                    }
+                });
+            ui.end_row();
+
+            ui.label("System Prompt:");
+            ui.add(
+                TextEdit::multiline(&mut workbench.system_prompt)
+                    .desired_rows(4)
+                    .desired_width(f32::INFINITY)
+                    .hint_text("e.g. You are a concise assistant that answers in bullet points."),
+            );
+            ui.end_row();
+
+            ui.label("Parameters:");
+            ui.horizontal(|ui| {
+                ui.add(DragValue::new(&mut workbench.temperature).range(0.0..=2.0).speed(0.05).prefix("temperature "));
+                ui.add(DragValue::new(&mut workbench.top_p).range(0.0..=1.0).speed(0.01).prefix("top_p "));
+                ui.add(DragValue::new(&mut workbench.num_ctx).range(0..=1_048_576).speed(256).prefix("num_ctx "))
+                    .on_hover_text("Context window size. 0 keeps the model's default.");
+            });
+            ui.end_row();
+
+            ui.label("Test Prompt:");
+            ui.add(
+                TextEdit::multiline(&mut workbench.test_prompt)
+                    .desired_rows(2)
+                    .desired_width(f32::INFINITY)
+                    .hint_text("A message to try the configuration with"),
+            );
+            ui.end_row();
+        });
+
+    ui.add_space(6.0);
+    let can_test = !is_busy && !workbench.base_model.is_empty() && !workbench.test_prompt.trim().is_empty();
+    let mut test_clicked = false;
+    ui.horizontal(|ui| {
+        test_clicked = ui
+            .add_enabled(can_test && app.rt.is_some(), Button::new("▶ Test"))
+            .on_hover_text("Generate a response with /api/generate (nothing is saved)")
+            .clicked();
+        ui.add_enabled(!is_busy, egui::Checkbox::new(&mut app.workbench.stream, "Stream"))
+            .on_hover_text("Show the response token by token as it is generated, and allow stopping it");
+        if matches!(current_status, AppStatus::Generating(_)) {
+            ui.spinner();
+            ui.label("Generating...");
+            if app.workbench.stream
+                && ui.button("⏹ Stop").on_hover_text("Stop the generation, keeping the response so far").clicked()
+            {
+                info!("Stopping the workbench generation.");
+                app.workbench.cancel_generation.store(true, Ordering::Relaxed);
+            }
+        }
+    });
+    if test_clicked {
+        start_test_generation(app);
+    }
+
+    // Last test output
+    match &app.workbench.output {
+        Some(Ok(response)) => {
+            ScrollArea::vertical()
+                .id_salt("workbench_output")
+                .max_height(200.0)
+                .stick_to_bottom(true) // Follow a streamed response
+                .show(ui, |ui| {
+                    ui.add(
+                        TextEdit::multiline(&mut response.as_str())
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+            if let Some(rate) = app.workbench.tokens_per_sec {
+                ui.small(format!("{:.1} tokens/s", rate));
+            }
+        }
+        Some(Err(e)) => {
+            ui.colored_label(ui.visuals().error_fg_color, format!("Error: {}", e));
+            draw_error_details(ui, "workbench_error_details", e);
+        }
+        None => {}
+    }
+
+    ui.separator();
+
+    // Save the configuration as a new model
+    let name_exists = model_names.contains(&app.workbench.new_model_name.trim().to_string());
+    let mut create_clicked = false;
+    ui.horizontal(|ui| {
+        ui.label("New Model Name:");
+        ui.add(
+            TextEdit::singleline(&mut app.workbench.new_model_name)
+                .hint_text("e.g. my-assistant:latest")
+                .desired_width(200.0),
+        );
+        let can_create = !is_busy
+            && app.rt.is_some()
+            && !app.workbench.base_model.is_empty()
+            && !app.workbench.new_model_name.trim().is_empty();
+        create_clicked = ui
+            .add_enabled(can_create, Button::new("💾 Create Model"))
+            .on_hover_text("Save the base model, system prompt and parameters as a new model with /api/create (Ollama 0.5.5+)")
+            .clicked();
+        if matches!(current_status, AppStatus::CreatingModel(_)) {
+            ui.spinner();
+        }
+    });
+    if name_exists {
+        ui.colored_label(ui.visuals().warn_fg_color, "⚠ A model with this name exists and will be replaced.");
+    }
+    if create_clicked {
+        start_model_creation(app);
+    }
+}
+
+// --- Actions ---
+
+// Returns the model options for the workbench parameters (num_ctx only when set).
+fn workbench_options(workbench: &WorkbenchState) -> HashMap<String, serde_json::Value> {
+    let mut options = HashMap::new();
+    options.insert("temperature".to_string(), serde_json::json!(workbench.temperature));
+    options.insert("top_p".to_string(), serde_json::json!(workbench.top_p));
+    if workbench.num_ctx > 0 {
+        options.insert("num_ctx".to_string(), serde_json::json!(workbench.num_ctx));
+    }
+    options
+}
+
+// Spawns the test generation for the current workbench inputs.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+fn start_test_generation(app: &mut OllamaPullerApp) {
+    let request_body = OllamaGenerateRequest {
+        model: app.workbench.base_model.clone(),
+        system: app.workbench.system_prompt.clone(),
+        prompt: app.workbench.test_prompt.clone(),
+        options: workbench_options(&app.workbench),
+        stream: app.workbench.stream,
+    };
+    let config = app.get_current_config();
+    let sender = app.task_update_sender.clone();
+    // A fresh flag per generation, so a late Stop click can't cancel the next one
+    let cancel = Arc::new(std::sync::atomic::AtomicBool::new(false));
+    app.workbench.cancel_generation = cancel.clone();
+
+    info!("Testing the workbench configuration on '{}'.", request_body.model);
+    *app.status.lock().unwrap() = AppStatus::Generating(request_body.model.clone());
+    *app.status_text.lock().unwrap() = format!("Generating a test response with {}...", request_body.model);
+    // Streamed tokens are appended to the (empty) output as they arrive
+    app.workbench.output = if request_body.stream { Some(Ok(String::new())) } else { None };
+    app.workbench.tokens_per_sec = None;
+
+    app.spawn_task(async move {
+        let result = if request_body.stream {
+            ollama::generate_stream_async(&request_body, &config, sender.clone(), cancel).await
+        } else {
+            ollama::generate_async(&request_body, &config).await.map(|response| {
+                let _ = sender.send(UpdateMessage::WorkbenchOutput(Ok(response)));
+                GenerateEnd::Done(None)
+            })
+        };
+        match result {
+            Ok(GenerateEnd::Done(tokens_per_sec)) => {
+                if let Some(rate) = tokens_per_sec {
+                    let _ = sender.send(UpdateMessage::WorkbenchTokensPerSec(rate));
+                }
+                let _ = sender.send(UpdateMessage::StatusText("Test response generated.".to_string()));
+                let _ = sender.send(UpdateMessage::Status(AppStatus::Success));
+            }
+            Ok(GenerateEnd::Cancelled) => {
+                info!("Workbench generation with '{}' stopped.", request_body.model);
+                let _ = sender.send(UpdateMessage::Log(format!(
+                    "INFO: Generation with '{}' stopped.",
+                    request_body.model
+                )));
+                let _ = sender.send(UpdateMessage::StatusText("Generation stopped.".to_string()));
+                let _ = sender.send(UpdateMessage::Status(AppStatus::Success));
+            }
+            Err(e) => {
+                let err_msg = format!("Failed to generate with {}: {}", request_body.model, e);
+                error!("{}", err_msg);
+                let _ = sender.send(UpdateMessage::Log(format!("ERROR: {}", err_msg)));
+                let _ = sender.send(UpdateMessage::WorkbenchOutput(Err(err_msg.clone())));
+                let _ = sender.send(UpdateMessage::StatusText(err_msg.clone()));
+                let _ = sender.send(UpdateMessage::Status(AppStatus::Error(err_msg)));
+            }
+        }
+    });
+}
+
+// Validates the new model name and spawns its creation, refreshing the model list afterwards.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+fn start_model_creation(app: &mut OllamaPullerApp) {
+    let new_model_name = app.workbench.new_model_name.trim().to_string();
+    if let Err(e) = validate_model_identifier(&new_model_name) {
+        warn!("Invalid model name: {}", e);
+        let _ = app.task_update_sender.send(UpdateMessage::Log(format!("WARN: Invalid model name: {}", e)));
+        *app.status_text.lock().unwrap() = format!("Invalid model name: {}", e);
+        return;
+    }
+    let request_body = OllamaCreateRequest {
+        model: new_model_name,
+        from: app.workbench.base_model.clone(),
+        system: app.workbench.system_prompt.clone(),
+        parameters: workbench_options(&app.workbench),
+        stream: false,
+    };
+    let config = app.get_current_config();
+    let sender = app.task_update_sender.clone();
+
+    info!("Creating model '{}' from '{}'.", request_body.model, request_body.from);
+    *app.status.lock().unwrap() = AppStatus::CreatingModel(request_body.model.clone());
+    *app.status_text.lock().unwrap() = format!("Creating model {}...", request_body.model);
+
+    app.spawn_task(async move {
+        match ollama::create_model_async(&request_body, &config).await {
+            Ok(_) => {
+                info!("Model '{}' created.", request_body.model);
+                let _ = sender.send(UpdateMessage::Log(format!(
+                    "INFO: Model '{}' created from '{}'.",
+                    request_body.model, request_body.from
+                )));
+                // Show the new model in the Manage view
+                if let Ok(models) = ollama::list_models_async(&config, sender.clone()).await {
+                    let _ = sender.send(UpdateMessage::ModelList(config.host_generation, models));
+                }
+                let _ = sender.send(UpdateMessage::StatusText(format!("Model '{}' created.", request_body.model)));
+                let _ = sender.send(UpdateMessage::Status(AppStatus::Success));
+            }
+            Err(e) => {
+                let err_msg = format!("Failed to create model {}: {}", request_body.model, e);
+                error!("{}", err_msg);
+                let _ = sender.send(UpdateMessage::Log(format!("ERROR: {}", err_msg)));
+                let _ = sender.send(UpdateMessage::StatusText(err_msg.clone()));
+                let _ = sender.send(UpdateMessage::Status(AppStatus::Error(err_msg)));
+            }
+        }
+    });
+}
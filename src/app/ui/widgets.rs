@@ -2,7 +2,7 @@
 // Contains drawing functions for reusable UI widgets, such as the log view content area.
 
 use crate::app::OllamaPullerApp; // Import main application state struct
-use egui::{Align, Layout, RichText, ScrollArea, TextWrapMode, Ui}; // egui components
+use egui::{Align, Context, Key, Layout, Modifiers, RichText, ScrollArea, TextWrapMode, Ui}; // egui components
 
 // --- Widget Drawing Functions ---
 
@@ -14,10 +14,17 @@ use egui::{Align, Layout, RichText, ScrollArea, TextWrapMode, Ui}; // egui compo
 // * app - Mutable reference to the main application state (still OllamaPullerApp - I should change that at some point). TODO STEVE
 // * ui - Mutable reference to the egui UI context for drawing.
 pub fn draw_log_view_content(app: &mut OllamaPullerApp, ui: &mut Ui) {
+    // Jump to the newest lines once, e.g. to show the error the panel was expanded for
+    let scroll_to_newest = std::mem::take(&mut app.scroll_logs_to_newest);
     // Use a vertical ScrollArea to contain the logs
-    ScrollArea::vertical()
-        .stick_to_bottom(true)
-        .auto_shrink([false, false])
+    // (newest-first mode has the latest lines at the top, so there is no bottom to stick to)
+    let mut scroll_area = ScrollArea::vertical()
+        .stick_to_bottom(!app.settings.logs_newest_first)
+        .auto_shrink([false, false]);
+    if scroll_to_newest && app.settings.logs_newest_first {
+        scroll_area = scroll_area.vertical_scroll_offset(0.0);
+    }
+    scroll_area
         .show(ui, |ui| {
             // Ensure the label uses the full available width and doesn't center text
             ui.with_layout(Layout::top_down(Align::LEFT), |ui| {
@@ -26,6 +33,49 @@ pub fn draw_log_view_content(app: &mut OllamaPullerApp, ui: &mut Ui) {
                     egui::Label::new(RichText::new(&app.logs_string_cache).monospace())
                         .wrap_mode(TextWrapMode::Extend),
                 );
+                if scroll_to_newest && !app.settings.logs_newest_first {
+                    ui.scroll_to_cursor(Some(Align::BOTTOM));
+                }
             });
         });
 }
+
+// Draws a collapsible "Details" section showing the full (wrapped) error message.
+// The single-line error labels can cut long messages off; this avoids digging through the logs.
+//
+// # Arguments
+//
+// * ui - Mutable reference to the egui UI context for drawing.
+// * id_salt - Unique id so each view keeps its own open/closed state.
+// * error - The error string from AppStatus::Error.
+pub fn draw_error_details(ui: &mut Ui, id_salt: &str, error: &str) {
+    egui::CollapsingHeader::new("Details")
+        .id_salt(id_salt)
+        .default_open(false)
+        .show(ui, |ui| {
+            ui.add(
+                egui::Label::new(RichText::new(error).monospace())
+                    .wrap_mode(TextWrapMode::Wrap)
+                    .selectable(true),
+            );
+        });
+}
+
+// Returns whether Enter (confirm) and Escape (cancel) were pressed for an open dialog, consuming both keys.
+// They are left alone while a text field has focus: there, Enter only finishes the edit and Escape only
+// leaves the field, so typing in a dialog never confirms or closes it by accident.
+//
+// # Arguments
+//
+// * ctx - The egui context (&egui::Context).
+pub fn dialog_keys(ctx: &Context) -> (bool, bool) {
+    if ctx.wants_keyboard_input() {
+        return (false, false);
+    }
+    ctx.input_mut(|i| {
+        (
+            i.consume_key(Modifiers::NONE, Key::Enter),
+            i.consume_key(Modifiers::NONE, Key::Escape),
+        )
+    })
+}
@@ -4,9 +4,11 @@
 // --- Necessary imports ---
 use crate::app::{
     config::{APP_NAME, SCRIPT_VERSION},
-    OllamaPullerApp,
+    utils::{load_image_from_bytes, LOGO_BYTES},
+    OllamaPullerApp, ABOUT_SHORTCUT, QUICK_OPEN_SHORTCUT, SETTINGS_SHORTCUT, TOGGLE_LOGS_SHORTCUT,
 };
-use egui::{Align2, Context, Image, Window};
+use egui::{Align2, Context, Grid, Image, RichText, Window};
+use log::{info, warn};
 
 // --- Window Drawing Function ---
 
@@ -20,11 +22,26 @@ pub fn draw_about_window(app: &mut OllamaPullerApp, ctx: &Context) {
     let mut about_window_open = app.show_about_window;
     let mut close_button_clicked = false;
 
+    // Re-create the logo if it failed to load or its texture is gone (e.g. freed after a
+    // graphics context reset). Tried once per opening, so a broken image isn't decoded every frame.
+    let logo_is_valid = app
+        .logo_texture
+        .as_ref()
+        .is_some_and(|texture| ctx.tex_manager().read().meta(texture.id()).is_some());
+    if !logo_is_valid && !app.logo_reload_attempted {
+        app.logo_reload_attempted = true;
+        app.logo_texture = load_image_from_bytes(ctx, "logo", LOGO_BYTES);
+        match app.logo_texture {
+            Some(_) => info!("Reloaded the logo texture from the embedded image."),
+            None => warn!("Reloading the logo texture failed, showing the fallback text."),
+        }
+    }
+
     Window::new("About LlamaLift")
         .open(&mut about_window_open)
         .collapsible(false)
         .resizable(false)
-        .default_size(egui::vec2(350.0, 380.0))
+        .default_size(egui::vec2(350.0, 460.0))
         .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
         .show(ctx, |ui| {
             ui.vertical_centered(|ui| {
@@ -48,6 +65,9 @@ pub fn draw_about_window(app: &mut OllamaPullerApp, ctx: &Context) {
                 // App Name and Version
                 ui.heading(APP_NAME);
                 ui.label(format!("Version: {}", SCRIPT_VERSION));
+                if let Some((version, url)) = app.available_update() {
+                    ui.hyperlink_to(format!("Update available: v{}", version), url);
+                }
                 ui.add_space(15.0); // More space before links
 
                 // Links Section
@@ -64,7 +84,27 @@ pub fn draw_about_window(app: &mut OllamaPullerApp, ctx: &Context) {
                     });
                 });
 
-                ui.add_space(30.0);
+                ui.add_space(15.0);
+
+                // Keyboard Shortcuts
+                ui.label(RichText::new("Keyboard Shortcuts").strong());
+                Grid::new("about_shortcuts_grid")
+                    .num_columns(2)
+                    .spacing([20.0, 2.0])
+                    .show(ui, |ui| {
+                        for (shortcut, action) in [
+                            (&SETTINGS_SHORTCUT, "Settings"),
+                            (&ABOUT_SHORTCUT, "About"),
+                            (&QUICK_OPEN_SHORTCUT, "Jump to Model"),
+                            (&TOGGLE_LOGS_SHORTCUT, "Show/hide logs"),
+                        ] {
+                            ui.monospace(ctx.format_shortcut(shortcut));
+                            ui.label(action);
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(20.0);
 
                 // Close Button
                 if ui.button("Close").clicked() {
@@ -79,4 +119,7 @@ pub fn draw_about_window(app: &mut OllamaPullerApp, ctx: &Context) {
         about_window_open = false;
     }
     app.show_about_window = about_window_open;
+    if !about_window_open {
+        app.logo_reload_attempted = false; // Allow another attempt the next time it opens
+    }
 }
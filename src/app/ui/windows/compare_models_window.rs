@@ -0,0 +1,123 @@
+// src/app/ui/windows/compare_models_window.rs
+// Contains the drawing function for the side-by-side model comparison window.
+
+// --- Necessary imports ---
+use crate::app::{ollama::OllamaModel, OllamaPullerApp};
+use egui::{Align2, Context, Grid, RichText, ScrollArea, Window};
+
+// --- Window Drawing Function ---
+
+// Draws the "Compare Models" window for the models selected in the Manage Models view.
+// Basic data comes from the cached model list; the context length needs `/api/show`,
+// which is fetched on demand and cached in app.model_details.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (&egui::Context).
+pub fn draw_compare_models_window(app: &mut OllamaPullerApp, ctx: &Context) {
+    let mut window_open = app.show_compare_window;
+
+    // Keep the order of the current table sort for the columns
+    let models: Vec<OllamaModel> = app
+        .manage_view_cache
+        .iter()
+        .filter(|m| app.selected_models.contains(&m.name))
+        .cloned()
+        .collect();
+
+    // Request details for any model we don't have yet
+    for model in &models {
+        app.fetch_model_details(&model.name);
+    }
+
+    Window::new("Compare Models")
+        .open(&mut window_open)
+        .collapsible(false)
+        .resizable(true)
+        .default_width(500.0)
+        .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            if models.len() < 2 {
+                ui.label("Select at least two models in the Manage Models view to compare them.");
+                return;
+            }
+
+            ScrollArea::horizontal().show(ui, |ui| {
+                Grid::new("compare_models_grid")
+                    .num_columns(models.len() + 1)
+                    .spacing([20.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label("");
+                        for model in &models {
+                            ui.label(RichText::new(&model.name).strong());
+                        }
+                        ui.end_row();
+
+                        let text_or_dash = |value: &Option<String>| {
+                            value.clone().unwrap_or_else(|| "-".to_string())
+                        };
+
+                        ui.label("Size");
+                        for model in &models {
+                            ui.label(&model.size_human);
+                        }
+                        ui.end_row();
+
+                        ui.label("Parameter Size");
+                        for model in &models {
+                            ui.label(text_or_dash(&model.details.parameter_size));
+                        }
+                        ui.end_row();
+
+                        ui.label("Quantization Level");
+                        for model in &models {
+                            ui.label(text_or_dash(&model.details.quantization_level));
+                        }
+                        ui.end_row();
+
+                        ui.label("Family");
+                        for model in &models {
+                            ui.label(text_or_dash(&model.details.family));
+                        }
+                        ui.end_row();
+
+                        ui.label("Format");
+                        for model in &models {
+                            ui.label(text_or_dash(&model.details.format));
+                        }
+                        ui.end_row();
+
+                        ui.label("Context Length");
+                        for model in &models {
+                            match app.model_details.get(&model.name) {
+                                Some(Ok(show)) => {
+                                    ui.label(
+                                        show.context_length()
+                                            .map(|len| len.to_string())
+                                            .unwrap_or_else(|| "-".to_string()),
+                                    );
+                                }
+                                Some(Err(e)) => {
+                                    ui.colored_label(ui.visuals().error_fg_color, "Failed")
+                                        .on_hover_text(e);
+                                }
+                                None => {
+                                    ui.spinner();
+                                }
+                            }
+                        }
+                        ui.end_row();
+
+                        ui.label("Modified");
+                        for model in &models {
+                            ui.label(text_or_dash(&model.modified_local));
+                        }
+                        ui.end_row();
+                    });
+            });
+        });
+
+    app.show_compare_window = window_open;
+}
@@ -2,7 +2,7 @@
 // Contains the drawing function for the model deletion confirmation window.
 
 // --- Necessary imports ---
-use crate::app::OllamaPullerApp;
+use crate::app::{ui::widgets::dialog_keys, utils::format_size, OllamaPullerApp};
 use egui::{Align2, Color32, Context, Layout, RichText, Window};
 
 // --- Window Drawing Function ---
@@ -28,7 +28,17 @@ pub fn draw_delete_confirmation_window(
     // Check if there is a model queued for deletion confirmation
     if let Some(model_name) = &app.model_to_delete {
         let mut open = true; // Controls window visibility, closing sets it to false
+        let (enter_pressed, escape_pressed) = dialog_keys(ctx);
         let model_name_display = model_name.clone(); // Clone for display inside closure
+        // Look up the size of the model so the user can see how much space will be freed
+        let model_size = app
+            .listed_models
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|m| &m.name == model_name)
+            .map(|m| m.size)
+            .filter(|size| *size > 0); // A size of 0 means the model didn't report one
 
         Window::new("Confirm Deletion")
             .collapsible(false)
@@ -40,6 +50,10 @@ pub fn draw_delete_confirmation_window(
                     "Are you sure you want to permanently delete the model '{}'?", // Display model name
                     model_name_display
                 ));
+                if let Some(size) = model_size {
+                    ui.label(format!("This will free {}.", format_size(size)))
+                        .on_hover_text("Layers shared with other models are only freed once no model uses them.");
+                }
                 ui.add_space(10.0);
                 ui.horizontal(|ui| {
                     // Layout buttons from right to left
@@ -47,18 +61,24 @@ pub fn draw_delete_confirmation_window(
                         ui.add_space(10.0); // Spacing on the right - Delete button (styled red)
                         if ui
                             .button(RichText::new("Delete").color(Color32::RED))
+                            .on_hover_text("Enter")
                             .clicked()
                         {
                             result = Some(true); // Signal confirmation
                         }
                         ui.add_space(10.0); // Spacing between buttons
-                        if ui.button("Cancel").clicked() {
+                        if ui.button("Cancel").on_hover_text("Esc").clicked() {
                             result = Some(false);
                         }
                     });
                 });
             });
 
+        if result.is_none() && enter_pressed {
+            result = Some(true);
+        } else if result.is_none() && escape_pressed {
+            result = Some(false);
+        }
         // If the window was closed (either by 'X' or buttons), treat as cancel unless confirmed
         if !open && result.is_none() {
             result = Some(false); 
@@ -0,0 +1,98 @@
+// src/app/ui/windows/diagnostics_window.rs
+// Contains the drawing function for the Connection Diagnostics window (Help -> Connection Diagnostics).
+
+// --- Necessary imports ---
+use crate::app::{state::DIAGNOSTIC_STEPS, utils::redact_url_credentials, OllamaPullerApp};
+use egui::{Align2, Context, Grid, Label, RichText, TextWrapMode, Window};
+
+// --- Window Drawing Function ---
+
+// Draws the "Connection Diagnostics" window: the steps of the last diagnostics run against the
+// configured host (URL, DNS, TCP, API endpoints) with pass/fail, timing and details. Results appear
+// as each step completes; steps after a failure are skipped.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (&egui::Context).
+pub fn draw_diagnostics_window(app: &mut OllamaPullerApp, ctx: &Context) {
+    let mut window_open = app.show_diagnostics;
+    let mut run_clicked = false;
+    let failed = app.diagnostic_steps.iter().any(|step| step.result.is_err());
+    let finished = failed || app.diagnostic_steps.len() == DIAGNOSTIC_STEPS.len();
+
+    Window::new("Connection Diagnostics")
+        .open(&mut window_open)
+        .collapsible(false)
+        .resizable(true)
+        .default_width(560.0)
+        .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label(format!("Host: {}", redact_url_credentials(&app.diagnostics_host)));
+            ui.add_space(4.0);
+            Grid::new("diagnostics_grid")
+                .num_columns(4)
+                .spacing([12.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for (index, name) in DIAGNOSTIC_STEPS.iter().enumerate() {
+                        match app.diagnostic_steps.iter().find(|step| step.index == index) {
+                            Some(step) => {
+                                match &step.result {
+                                    Ok(_) => ui.colored_label(egui::Color32::from_rgb(0, 160, 0), "✔"),
+                                    Err(_) => ui.colored_label(ui.visuals().error_fg_color, "✘"),
+                                };
+                                ui.label(*name);
+                                ui.label(format!("{} ms", step.elapsed_ms));
+                                let detail = match &step.result {
+                                    Ok(detail) | Err(detail) => detail,
+                                };
+                                ui.add(Label::new(detail.as_str()).wrap_mode(TextWrapMode::Wrap).selectable(true));
+                            }
+                            None if !finished => {
+                                ui.spinner();
+                                ui.label(*name);
+                                ui.label("");
+                                ui.label("");
+                            }
+                            None => {
+                                ui.weak("–");
+                                ui.weak(*name);
+                                ui.label("");
+                                ui.weak("Skipped (an earlier step failed)");
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+            ui.add_space(6.0);
+            if finished {
+                if failed {
+                    ui.label(RichText::new("The first failing step shows where the connection breaks.").small());
+                } else {
+                    ui.label(RichText::new("All checks passed.").small());
+                }
+            }
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(finished, egui::Button::new("🔄 Run Again"))
+                    .on_hover_text("Run the checks again, e.g. after changing the host or a firewall rule")
+                    .clicked()
+                {
+                    run_clicked = true;
+                }
+                if ui
+                    .add_enabled(finished, egui::Button::new("📋 Copy"))
+                    .on_hover_text("Copy the results, e.g. for a bug report")
+                    .clicked()
+                {
+                    ui.ctx().copy_text(app.diagnostics_report());
+                }
+            });
+        });
+
+    if run_clicked {
+        app.run_diagnostics();
+    }
+    app.show_diagnostics = window_open;
+}
@@ -0,0 +1,49 @@
+// src/app/ui/windows/effective_config_window.rs
+// Contains the drawing function for the read-only Effective Configuration window (Help -> Effective Configuration).
+
+// --- Necessary imports ---
+use crate::app::{config, OllamaPullerApp};
+use egui::{Context, ScrollArea, TextEdit, Window};
+use log::info;
+
+// --- Window Drawing Function ---
+
+// Draws the "Effective Configuration" window: the configuration the app runs with (see
+// `config::effective_config_json`) as monospace JSON, with Copy and Refresh buttons. Nothing here can be
+// edited; changes go through the Settings window.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (&egui::Context).
+pub fn draw_effective_config_window(app: &mut OllamaPullerApp, ctx: &Context) {
+    let mut window_open = app.show_effective_config;
+
+    Window::new("Effective Configuration")
+        .open(&mut window_open)
+        .collapsible(false)
+        .resizable(true)
+        .default_size(egui::vec2(520.0, 420.0))
+        .show(ctx, |ui| {
+            ui.label("What LlamaLift actually uses: runtime values after fallbacks, the environment and the saved settings. Credentials in URLs are hidden.");
+            ui.horizontal(|ui| {
+                if ui.button("📋 Copy").on_hover_text("Copy as JSON, e.g. for a bug report").clicked() {
+                    ui.ctx().copy_text(app.effective_config_text.clone());
+                    info!("Copied the effective configuration to the clipboard.");
+                }
+                if ui.button("🔄 Refresh").on_hover_text("Show changes saved since this window was opened").clicked() {
+                    app.effective_config_text = config::effective_config_json(&app.settings, app.config_path.as_deref());
+                }
+            });
+            ui.separator();
+            ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
+                ui.add(
+                    TextEdit::multiline(&mut app.effective_config_text.as_str())
+                        .code_editor()
+                        .desired_width(f32::INFINITY),
+                );
+            });
+        });
+
+    app.show_effective_config = window_open;
+}
@@ -0,0 +1,213 @@
+// src/app/ui/windows/maintenance_window.rs
+// Contains the drawing function for the model store Maintenance window (File -> Maintenance).
+
+// --- Necessary imports ---
+use crate::app::{
+    maintenance::{self, MaintenanceReport},
+    state::{AppStatus, UpdateMessage},
+    utils::format_size,
+    OllamaPullerApp,
+};
+use egui::{Align2, Button, Context, Grid, RichText, ScrollArea, Window};
+use log::{error, info};
+
+// --- Window Drawing Function ---
+
+// Draws the "Maintenance" window: scans the local model store for leftovers of interrupted pulls
+// (partial downloads, unreferenced blobs) and for models whose blobs don't match their manifest.
+// Scanning and deleting are only offered while nothing else runs; deleting needs a second confirmation
+// and only removes what a fresh scan still finds to be a leftover.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (&egui::Context).
+pub fn draw_maintenance_window(app: &mut OllamaPullerApp, ctx: &Context) {
+    let mut window_open = app.show_maintenance_window;
+    let is_busy = !matches!(
+        *app.status.lock().unwrap(),
+        AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+    );
+    let models_dir = app.maintenance_models_dir.clone(); // Resolved when the window opened
+    let mut scan_clicked = false;
+    let mut cleanup_confirmed = false;
+
+    Window::new("Maintenance")
+        .open(&mut window_open)
+        .collapsible(false)
+        .resizable(true)
+        .default_width(500.0)
+        .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            let Some(models_dir) = &models_dir else {
+                ui.label("No local model store found, so there is nothing to check here (Ollama may run on another machine).");
+                ui.label("If it is on this machine, set its location under Settings → Model Store.");
+                return;
+            };
+            ui.label(format!("Model store: {}", models_dir.display()));
+            ui.horizontal(|ui| {
+                // A running pull's finished layers aren't referenced by a manifest yet, so don't scan then
+                scan_clicked = ui
+                    .add_enabled(!is_busy, Button::new("🔍 Scan"))
+                    .on_hover_text("Look for partial downloads, unreferenced blobs and incomplete models (reads the store directory only)")
+                    .on_disabled_hover_text("Wait for the running operation to finish")
+                    .clicked();
+                if let Some(report) = &app.maintenance_report {
+                    ui.label(format!(
+                        "{} incomplete model(s), {} leftover file(s) ({})",
+                        report.incomplete_models.len(),
+                        report.removable_files().count(),
+                        format_size(report.removable_size())
+                    ));
+                }
+            });
+            ui.small("Ollama removes unused blobs itself when it starts, unless OLLAMA_NOPRUNE is set.");
+
+            let Some(report) = &app.maintenance_report else { return };
+            ui.separator();
+            ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                draw_report(ui, report);
+            });
+
+            if report.removable_files().count() == 0 {
+                return;
+            }
+            ui.separator();
+            if !app.maintenance_confirm_cleanup {
+                if ui
+                    .add_enabled(!is_busy, Button::new(format!(
+                        "🗑 Clean Up {} File(s) ({})",
+                        report.removable_files().count(),
+                        format_size(report.removable_size())
+                    )))
+                    .on_hover_text("Delete the partial downloads and unreferenced blobs listed above")
+                    .clicked()
+                {
+                    app.maintenance_confirm_cleanup = true;
+                }
+            } else {
+                ui.colored_label(
+                    ui.visuals().warn_fg_color,
+                    "⚠ Make sure no pull is running, also not from the ollama CLI or another app: its partial files would be deleted.",
+                );
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(!is_busy, Button::new("Delete")).clicked() {
+                        cleanup_confirmed = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        app.maintenance_confirm_cleanup = false;
+                    }
+                });
+            }
+        });
+
+    if let Some(models_dir) = models_dir.filter(|_| scan_clicked || cleanup_confirmed) {
+        if cleanup_confirmed {
+            if let Some(report) = app.maintenance_report.take() {
+                // The shown report may be stale (e.g. a pull finished since), so only delete what a
+                // fresh scan still finds to be partial or unreferenced
+                let files = match maintenance::scan_model_store(&models_dir) {
+                    Ok(fresh) => report.still_removable(&fresh),
+                    Err(e) => {
+                        error!("Model store scan before the cleanup failed, nothing deleted: {}", e);
+                        let _ = app.task_update_sender.send(UpdateMessage::Log(format!(
+                            "ERROR: Model store scan before the cleanup failed, nothing deleted: {}",
+                            e
+                        )));
+                        Vec::new()
+                    }
+                };
+                let skipped = report.removable_files().count() - files.len();
+                if skipped > 0 {
+                    info!("Maintenance: keeping {} file(s) that are no longer leftovers.", skipped);
+                    let _ = app.task_update_sender.send(UpdateMessage::Log(format!(
+                        "INFO: Maintenance: keeping {} file(s) that are no longer leftovers.",
+                        skipped
+                    )));
+                }
+                let (freed, errors) = maintenance::remove_files(files.iter());
+                let summary = format!("Maintenance: freed {} in {}.", format_size(freed), models_dir.display());
+                info!("{}", summary);
+                let _ = app.task_update_sender.send(UpdateMessage::Log(format!("INFO: {}", summary)));
+                for e in &errors {
+                    let _ = app.task_update_sender.send(UpdateMessage::Log(format!("ERROR: Could not remove {}", e)));
+                }
+                *app.status_text.lock().unwrap() = summary;
+                app.update_model_store_space();
+            }
+        }
+        // Scan again after a cleanup, so the window shows what is left
+        match maintenance::scan_model_store(&models_dir) {
+            Ok(report) => app.maintenance_report = Some(report),
+            Err(e) => {
+                error!("Model store scan failed: {}", e);
+                let _ = app.task_update_sender.send(UpdateMessage::Log(format!("ERROR: Model store scan failed: {}", e)));
+                app.maintenance_report = None;
+            }
+        }
+        app.maintenance_confirm_cleanup = false;
+    }
+
+    if !window_open {
+        app.maintenance_confirm_cleanup = false;
+    }
+    app.show_maintenance_window = window_open;
+}
+
+// Draws the findings of a model store scan.
+fn draw_report(ui: &mut egui::Ui, report: &MaintenanceReport) {
+    if report.incomplete_models.is_empty()
+        && report.removable_files().count() == 0
+        && report.unreadable_manifests.is_empty()
+    {
+        ui.label("✔ Nothing to clean up, all models are complete.");
+        return;
+    }
+
+    if !report.incomplete_models.is_empty() {
+        ui.label(RichText::new("Incomplete models").strong());
+        ui.label("Their blobs are missing or have the wrong size, e.g. after an interrupted pull. Pull them again or delete them.");
+        Grid::new("maintenance_incomplete_grid")
+            .num_columns(3)
+            .spacing([20.0, 2.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for model in &report.incomplete_models {
+                    ui.label(&model.name);
+                    ui.label(format!("{} of {} on disk", format_size(model.on_disk_size), format_size(model.expected_size)));
+                    if model.missing_blobs > 0 {
+                        ui.label(format!("{} blob(s) missing", model.missing_blobs));
+                    } else {
+                        ui.label("");
+                    }
+                    ui.end_row();
+                }
+            });
+        ui.add_space(6.0);
+    }
+
+    for (title, files) in [
+        ("Partial downloads", &report.partial_files),
+        ("Unreferenced blobs", &report.unreferenced_blobs),
+    ] {
+        if files.is_empty() {
+            continue;
+        }
+        ui.label(RichText::new(format!("{} ({})", title, files.len())).strong());
+        for file in files {
+            let file_name = file.path.file_name().unwrap_or_default().to_string_lossy();
+            ui.label(RichText::new(format!("{}  {}", file_name, format_size(file.size))).monospace().small());
+        }
+        ui.add_space(6.0);
+    }
+
+    if !report.unreadable_manifests.is_empty() {
+        ui.colored_label(
+            ui.visuals().warn_fg_color,
+            "⚠ Some manifests could not be read, so unreferenced blobs are not listed:",
+        );
+        for manifest in &report.unreadable_manifests {
+            ui.label(RichText::new(manifest).small());
+        }
+    }
+}
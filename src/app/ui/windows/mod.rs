@@ -1,7 +1,16 @@
 // src/app/ui/windows/mod.rs
-// Declares the modules for individual UI windows (About, Settings, Delete, Select Columns).
+// Declares the modules for individual UI windows (About, Settings, Delete, Select Columns, Compare, Runtime Error, Reload Config, Pull Confirmation, Quick Open, Maintenance, Effective Config, Model Info, Diagnostics).
 
 pub mod about_window;
+pub mod compare_models_window;
 pub mod delete_confirmation_window;
+pub mod diagnostics_window;
+pub mod effective_config_window;
+pub mod maintenance_window;
+pub mod model_info_window;
+pub mod pull_confirmation_window;
+pub mod quick_open_window;
+pub mod reload_config_window;
+pub mod runtime_error_window;
 pub mod select_columns_window;
 pub mod settings_window;
@@ -0,0 +1,136 @@
+// src/app/ui/windows/model_info_window.rs
+// Contains the drawing function for the model Info window (ℹ button of a Manage Models row).
+
+// --- Necessary imports ---
+use crate::app::{ollama::OllamaModel, state::UpdateMessage, utils::format_bytes_grouped, OllamaPullerApp};
+use egui::{Align2, Context, Grid, RichText, Window};
+use log::info;
+
+// --- Window Drawing Function ---
+
+// Draws the "Model Info" window for app.info_model: everything known about one model in one place,
+// including the full digest (the table only shows it shortened). The list data comes from the cached
+// model list; the context length and lineage need `/api/show`, fetched on demand like for Compare.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (&egui::Context).
+pub fn draw_model_info_window(app: &mut OllamaPullerApp, ctx: &Context) {
+    let Some(model_name) = app.info_model.clone() else { return };
+    let mut window_open = true;
+
+    // The view cache has the display fields (local time, shortened digest) filled in
+    let model: Option<OllamaModel> = app.manage_view_cache.iter().find(|m| m.name == model_name).cloned();
+    if model.is_some() {
+        app.fetch_model_details(&model_name);
+    }
+
+    Window::new(format!("Model Info: {}", model_name))
+        .id(egui::Id::new("model_info_window"))
+        .open(&mut window_open)
+        .collapsible(false)
+        .resizable(true)
+        .default_width(450.0)
+        .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            let Some(model) = &model else {
+                ui.label("This model is no longer in the list (deleted, or hidden by the filter).");
+                return;
+            };
+            let text_or_dash = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+
+            Grid::new("model_info_grid")
+                .num_columns(2)
+                .spacing([20.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("Name");
+                    ui.label(RichText::new(&model.name).strong());
+                    ui.end_row();
+
+                    ui.label("Size");
+                    ui.label(format!("{} ({} bytes)", model.size_human, format_bytes_grouped(model.size)));
+                    ui.end_row();
+
+                    ui.label("Modified");
+                    ui.label(model.modified_local.clone().unwrap_or_else(|| model.modified_at.clone()));
+                    ui.end_row();
+
+                    ui.label("Digest");
+                    ui.horizontal(|ui| {
+                        let digest = if app.info_full_digest { &model.digest } else { &model.digest_display };
+                        ui.label(RichText::new(digest).monospace()).on_hover_text(&model.digest);
+                        let toggle_text = if app.info_full_digest { "Short" } else { "Full" };
+                        if ui
+                            .small_button(toggle_text)
+                            .on_hover_text("Switch between the full digest and the shortened form used in the table")
+                            .clicked()
+                        {
+                            app.info_full_digest = !app.info_full_digest;
+                        }
+                        if ui.small_button("📋").on_hover_text("Copy the full digest").clicked() {
+                            ui.ctx().copy_text(model.digest.clone());
+                            info!("Copied the digest of '{}' to clipboard.", model.name);
+                            let _ = app.task_update_sender.send(UpdateMessage::Log(format!(
+                                "INFO: Copied the digest of '{}' to clipboard.",
+                                model.name
+                            )));
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label("Format");
+                    ui.label(text_or_dash(&model.details.format));
+                    ui.end_row();
+
+                    ui.label("Family");
+                    ui.label(text_or_dash(&model.details.family));
+                    ui.end_row();
+
+                    ui.label("Families");
+                    ui.label(match model.details.families_list() {
+                        [] => "-".to_string(),
+                        families => families.join(", "),
+                    });
+                    ui.end_row();
+
+                    ui.label("Parameter Size");
+                    ui.label(text_or_dash(&model.details.parameter_size));
+                    ui.end_row();
+
+                    ui.label("Quantization Level");
+                    ui.label(text_or_dash(&model.details.quantization_level));
+                    ui.end_row();
+
+                    ui.label("Last Used");
+                    ui.label(model.last_used_local.clone().unwrap_or_else(|| "never".to_string()));
+                    ui.end_row();
+
+                    match app.model_details.get(&model.name) {
+                        Some(Ok(show)) => {
+                            ui.label("Context Length");
+                            ui.label(show.context_length().map_or_else(|| "-".to_string(), |len| len.to_string()));
+                            ui.end_row();
+                            ui.label("Base / Fine-tune");
+                            ui.label(show.lineage());
+                            ui.end_row();
+                        }
+                        Some(Err(e)) => {
+                            ui.label("Details");
+                            ui.colored_label(ui.visuals().error_fg_color, "Failed").on_hover_text(e);
+                            ui.end_row();
+                        }
+                        None => {
+                            ui.label("Details");
+                            ui.spinner();
+                            ui.end_row();
+                        }
+                    }
+                });
+        });
+
+    if !window_open {
+        app.info_model = None;
+    }
+}
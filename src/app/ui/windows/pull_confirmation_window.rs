@@ -0,0 +1,104 @@
+// src/app/ui/windows/pull_confirmation_window.rs
+// Contains the drawing function for the confirmation shown before pulling a large batch
+// or models known to be very large.
+
+// --- Necessary imports ---
+use crate::app::{
+    catalog::{known_model_size, large_models},
+    ui::widgets::dialog_keys,
+    utils::format_size,
+    OllamaPullerApp,
+};
+use egui::{Align2, Context, Layout, Window};
+
+// --- Window Drawing Function ---
+
+// Draws the modal confirmation dialog for the batch in app.pending_pull_confirmation.
+// Shows the model count and estimated total size for large batches, and lists the
+// models known to be large (each part only if the corresponding setting asks for it).
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (`&egui::Context`).
+//
+// # Returns
+//
+// * Some(true) if the user confirmed the pull.
+// * Some(false) if the user cancelled it (or closed the window).
+// * None if no answer was given yet (or no pull is awaiting confirmation).
+pub fn draw_pull_confirmation_window(
+    app: &mut OllamaPullerApp,
+    ctx: &Context,
+) -> Option<bool> {
+    let models_to_pull = app.pending_pull_confirmation.as_ref()?;
+    let mut result: Option<bool> = None;
+    let mut open = true; // Closing via 'X' counts as cancel
+    let (enter_pressed, escape_pressed) = dialog_keys(ctx);
+
+    let show_batch_size = app.settings.confirm_batch_size
+        && models_to_pull.len() > app.settings.batch_size_confirm_threshold;
+    let large = if app.settings.confirm_large_pulls {
+        large_models(models_to_pull, app.settings.large_pull_threshold_gib)
+    } else {
+        Vec::new()
+    };
+    let known_sizes: Vec<u64> = models_to_pull.iter().filter_map(|id| known_model_size(id)).collect();
+
+    Window::new("Confirm Download")
+        .collapsible(false)
+        .resizable(false)
+        .open(&mut open)
+        .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            if show_batch_size {
+                ui.label(format!("You are about to pull {} models.", models_to_pull.len()));
+                if !known_sizes.is_empty() {
+                    ui.label(format!(
+                        "Estimated size: at least ~{} ({} of {} models have a known size).",
+                        format_size(known_sizes.iter().sum()),
+                        known_sizes.len(),
+                        models_to_pull.len()
+                    ));
+                }
+            }
+            if !large.is_empty() {
+                if show_batch_size {
+                    ui.add_space(6.0);
+                }
+                ui.label(format!(
+                    "The following models are known to be larger than {} GiB:",
+                    app.settings.large_pull_threshold_gib
+                ));
+                for (model_id, size) in &large {
+                    ui.label(format!("• {} (~{})", model_id, format_size(*size)));
+                }
+            }
+            if !known_sizes.is_empty() {
+                ui.label("Sizes are approximate, from LlamaLift's bundled catalog.");
+            }
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add_space(10.0);
+                    if ui.button("Download").on_hover_text("Enter").clicked() {
+                        result = Some(true);
+                    }
+                    ui.add_space(10.0);
+                    if ui.button("Cancel").on_hover_text("Esc").clicked() {
+                        result = Some(false);
+                    }
+                });
+            });
+        });
+
+    if result.is_none() && enter_pressed {
+        result = Some(true);
+    } else if result.is_none() && escape_pressed {
+        result = Some(false);
+    }
+    if !open && result.is_none() {
+        result = Some(false);
+    }
+    result
+}
@@ -0,0 +1,162 @@
+// src/app/ui/windows/quick_open_window.rs
+// Contains the drawing function for the "Jump to Model" quick-open overlay (Ctrl/Cmd+P).
+
+// --- Necessary imports ---
+use crate::app::{
+    state::{AppStatus, AppView},
+    utils::{base_model_name, fuzzy_score},
+    OllamaPullerApp,
+};
+use egui::{Align2, Button, Context, Key, Modifiers, RichText, ScrollArea, TextEdit, Window};
+use log::{debug, info};
+use std::cmp::Reverse;
+
+/// Most matches listed at once; the best ones come first anyway.
+const MAX_QUICK_OPEN_RESULTS: usize = 50;
+
+// What to do with the model chosen in the quick-open list.
+enum QuickOpenAction {
+    GoTo(String),
+    Load(String),
+    Delete(String),
+}
+
+// --- Window Drawing Function ---
+
+// Draws the "Jump to Model" overlay: a search field and the models ranked by fuzzy score against it.
+// Up/Down move the selection, Enter jumps to the selected model in the Manage Models table (highlighting
+// and scrolling to it) and Escape closes the overlay. The row buttons load or delete a model instead.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (&egui::Context).
+pub fn draw_quick_open_window(app: &mut OllamaPullerApp, ctx: &Context) {
+    let mut window_open = app.show_quick_open;
+    let is_busy = !matches!(
+        *app.status.lock().unwrap(),
+        AppStatus::Idle | AppStatus::Success | AppStatus::Error(_)
+    );
+
+    // Rank the models; an empty query keeps the list order
+    let mut matches: Vec<(i64, String)> = app
+        .listed_models
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|m| fuzzy_score(&m.name, &app.quick_open_query).map(|score| (score, m.name.clone())))
+        .collect();
+    matches.sort_by_key(|(score, _)| Reverse(*score)); // Stable, so equal scores keep the list order
+    matches.truncate(MAX_QUICK_OPEN_RESULTS);
+    app.quick_open_selected = app.quick_open_selected.min(matches.len().saturating_sub(1));
+
+    // Handle the navigation keys before the text field sees them
+    let (move_up, move_down, enter, escape) = ctx.input_mut(|i| {
+        (
+            i.consume_key(Modifiers::NONE, Key::ArrowUp),
+            i.consume_key(Modifiers::NONE, Key::ArrowDown),
+            i.consume_key(Modifiers::NONE, Key::Enter),
+            i.consume_key(Modifiers::NONE, Key::Escape),
+        )
+    });
+    if move_up {
+        app.quick_open_selected = app.quick_open_selected.saturating_sub(1);
+    }
+    if move_down && app.quick_open_selected + 1 < matches.len() {
+        app.quick_open_selected += 1;
+    }
+
+    let mut action: Option<QuickOpenAction> = None;
+    if enter {
+        action = matches
+            .get(app.quick_open_selected)
+            .map(|(_, name)| QuickOpenAction::GoTo(name.clone()));
+    }
+
+    Window::new("Jump to Model")
+        .open(&mut window_open)
+        .collapsible(false)
+        .resizable(false)
+        .title_bar(false)
+        .default_width(400.0)
+        .anchor(Align2::CENTER_TOP, egui::vec2(0.0, 60.0))
+        .show(ctx, |ui| {
+            let query_response = ui.add(
+                TextEdit::singleline(&mut app.quick_open_query)
+                    .hint_text("Jump to model...")
+                    .desired_width(f32::INFINITY),
+            );
+            query_response.request_focus();
+            if query_response.changed() {
+                app.quick_open_selected = 0;
+            }
+            ui.separator();
+
+            if matches.is_empty() {
+                ui.label("No matching models.");
+                return;
+            }
+            ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                for (index, (_, name)) in matches.iter().enumerate() {
+                    let is_selected = index == app.quick_open_selected;
+                    ui.horizontal(|ui| {
+                        let label = ui.selectable_label(is_selected, RichText::new(name).monospace());
+                        if is_selected && (move_up || move_down) {
+                            label.scroll_to_me(None);
+                        }
+                        if label.on_hover_text("Go to this model").clicked() {
+                            action = Some(QuickOpenAction::GoTo(name.clone()));
+                        }
+                        if ui.add_enabled(!is_busy, Button::new("⚡").small())
+                            .on_hover_text("Load into memory")
+                            .clicked()
+                        {
+                            action = Some(QuickOpenAction::Load(name.clone()));
+                        }
+                        if ui.add_enabled(!is_busy, Button::new("🗑").small())
+                            .on_hover_text("Delete Model")
+                            .clicked()
+                        {
+                            action = Some(QuickOpenAction::Delete(name.clone()));
+                        }
+                    });
+                }
+            });
+            ui.separator();
+            ui.small("↑/↓ to select, Enter to go to the model, Esc to close");
+        });
+
+    if let Some(action) = action {
+        match action {
+            QuickOpenAction::GoTo(model_name) => {
+                debug!("Quick open: going to '{}'.", model_name);
+                if app.current_view != AppView::ManageModels {
+                    app.current_view = AppView::ManageModels;
+                    app.manage_view_cache_dirty = true; // The view switch was already checked this frame
+                }
+                // Make sure the model is visible in the grouped view as well
+                app.model_group_open.insert(base_model_name(&model_name).to_string(), true);
+                app.scroll_to_model = Some(model_name.clone());
+                app.highlighted_model = Some(model_name);
+            }
+            QuickOpenAction::Load(model_name) => {
+                info!("User requested loading model '{}' into memory (quick open).", model_name);
+                app.model_to_load = Some(model_name);
+            }
+            QuickOpenAction::Delete(model_name) => {
+                info!("User initiated delete for model '{}' (quick open). Showing confirmation.", model_name);
+                app.model_to_delete = Some(model_name);
+            }
+        }
+        window_open = false;
+    }
+    if escape {
+        window_open = false;
+    }
+
+    if !window_open {
+        app.quick_open_query.clear();
+        app.quick_open_selected = 0;
+    }
+    app.show_quick_open = window_open;
+}
@@ -0,0 +1,62 @@
+// src/app/ui/windows/reload_config_window.rs
+// Contains the drawing function for the confirmation shown before reloading the config file over unsaved changes.
+
+// --- Necessary imports ---
+use crate::app::{ui::widgets::dialog_keys, OllamaPullerApp};
+use egui::{Align2, Context, Layout, Window};
+
+// --- Window Drawing Function ---
+
+// Draws the confirmation dialog for reloading settings from disk.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (`&egui::Context`).
+//
+// # Returns
+//
+// * Some(true) if the user confirmed the reload.
+// * Some(false) if the user cancelled (or closed the window).
+// * None while the user has not decided yet.
+pub fn draw_reload_config_window(app: &mut OllamaPullerApp, ctx: &Context) -> Option<bool> {
+    let mut result: Option<bool> = None;
+    let mut open = true;
+    let (enter_pressed, escape_pressed) = dialog_keys(ctx);
+
+    Window::new("Reload Config from Disk")
+        .collapsible(false)
+        .resizable(false)
+        .open(&mut open)
+        .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label("There are settings changes that have not been saved to the config file yet.");
+            if let Some(path) = &app.config_path {
+                ui.label(format!("Discard them and reload '{}'?", path.display()));
+            } else {
+                ui.label("Discard them and reload the config file?");
+            }
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button("Reload").on_hover_text("Enter").clicked() {
+                        result = Some(true);
+                    }
+                    if ui.button("Cancel").on_hover_text("Esc").clicked() {
+                        result = Some(false);
+                    }
+                });
+            });
+        });
+
+    if result.is_none() && enter_pressed {
+        result = Some(true);
+    } else if result.is_none() && escape_pressed {
+        result = Some(false);
+    }
+    // Closing via 'X' counts as cancel
+    if !open && result.is_none() {
+        result = Some(false);
+    }
+    result
+}
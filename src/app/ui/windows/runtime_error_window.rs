@@ -0,0 +1,47 @@
+// src/app/ui/windows/runtime_error_window.rs
+// Contains the drawing function for the error dialog shown when the async runtime could not be started.
+
+// --- Necessary imports ---
+use crate::app::OllamaPullerApp;
+use egui::{Align2, Context, Window};
+
+// --- Window Drawing Function ---
+
+// Draws the "Network Features Unavailable" window.
+// Shown once on startup if the Tokio runtime failed to build; the app stays open
+// so the user sees the reason instead of an opaque crash.
+//
+// # Arguments
+//
+// * app - Mutable reference to the main application state (OllamaPullerApp).
+// * ctx - The egui context (&egui::Context).
+pub fn draw_runtime_error_window(app: &mut OllamaPullerApp, ctx: &Context) {
+    let mut window_open = app.show_runtime_error_window;
+    let mut ok_clicked = false;
+
+    Window::new("Network Features Unavailable")
+        .open(&mut window_open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.colored_label(
+                ui.visuals().error_fg_color,
+                app.runtime_error.as_deref().unwrap_or("Unknown error"),
+            );
+            ui.add_space(5.0);
+            ui.label("LlamaLift cannot talk to the Ollama server in this session, so listing, downloading and deleting models are disabled.");
+            ui.label("Restart LlamaLift to try again. Check the logs for details.");
+            ui.add_space(10.0);
+            ui.vertical_centered(|ui| {
+                if ui.button("OK").clicked() {
+                    ok_clicked = true;
+                }
+            });
+        });
+
+    if ok_clicked {
+        window_open = false;
+    }
+    app.show_runtime_error_window = window_open;
+}
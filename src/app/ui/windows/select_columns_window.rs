@@ -4,6 +4,7 @@
 // --- Necessary imports ---
 use crate::app::{
     state::UpdateMessage,
+    ui::widgets::dialog_keys,
     OllamaPullerApp,
 };
 use egui::{Align, Align2, Context, Layout, ScrollArea, Window};
@@ -23,6 +24,7 @@ pub fn draw_select_columns_window(app: &mut OllamaPullerApp, ctx: &Context) {
     let mut window_open = app.show_select_columns_window; // Control window visibility
     let mut ok_clicked = false;
     let mut cancel_clicked = false;
+    let (enter_pressed, escape_pressed) = dialog_keys(ctx);
 
     Window::new("Select Columns")
         .open(&mut window_open)
@@ -59,10 +61,10 @@ pub fn draw_select_columns_window(app: &mut OllamaPullerApp, ctx: &Context) {
             ui.horizontal(|ui| {
                 // Layout buttons from right to left
                 ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                    if ui.button("Cancel").clicked() {
+                    if ui.button("Cancel").on_hover_text("Esc").clicked() || escape_pressed {
                         cancel_clicked = true;
                     }
-                    if ui.button("OK").clicked() {
+                    if ui.button("OK").on_hover_text("Enter").clicked() || enter_pressed {
                         ok_clicked = true;
                     }
                 });
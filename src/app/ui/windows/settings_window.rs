@@ -3,16 +3,52 @@
 
 // --- Necessary imports ---
 use crate::app::{
+    config::{self, AppSettings, BACKOFF_MS_RANGE, MAX_MODEL_INPUTS, MAX_RETRIES_LIMIT},
     state::UpdateMessage,
+    ui::widgets::dialog_keys,
+    utils,
     OllamaPullerApp,
 };
 use chrono_tz::Tz;
 use egui::{
-    Align2, ComboBox, Context, Grid, TextEdit, Window,
+    Align2, CollapsingHeader, ComboBox, Context, Grid, ScrollArea, TextEdit, Window,
 };
 use log::{error, info};
 use std::str::FromStr;
 
+// --- Settings Categories ---
+
+// The sections of the Settings window, in display order. Only "General" starts expanded;
+// egui remembers which sections were opened.
+#[derive(Clone, Copy, PartialEq)]
+enum SettingsCategory {
+    General,
+    Downloads,
+    ManageModels,
+    Network,
+    Advanced,
+}
+
+impl SettingsCategory {
+    const ALL: [SettingsCategory; 5] = [
+        SettingsCategory::General,
+        SettingsCategory::Downloads,
+        SettingsCategory::ManageModels,
+        SettingsCategory::Network,
+        SettingsCategory::Advanced,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            SettingsCategory::General => "General",
+            SettingsCategory::Downloads => "Downloads",
+            SettingsCategory::ManageModels => "Manage Models",
+            SettingsCategory::Network => "Network & Polling",
+            SettingsCategory::Advanced => "Advanced",
+        }
+    }
+}
+
 // --- Window Drawing Function ---
 
 // Draws the "Settings" window and handles its interactions (Save, Cancel, Close).
@@ -28,6 +64,8 @@ pub fn draw_settings_window(app: &mut OllamaPullerApp, ctx: &Context) {
     // Flags to track button clicks within the window closure
     let mut save_and_close_clicked = false;
     let mut cancel_settings_clicked = false;
+    // Enter saves and Escape cancels, unless a text field (e.g. the timezone) is being edited
+    let (enter_pressed, escape_pressed) = dialog_keys(ctx);
 
     Window::new("Settings")
         .open(&mut settings_window_open) // Control visibility with the temporary boolean
@@ -35,15 +73,15 @@ pub fn draw_settings_window(app: &mut OllamaPullerApp, ctx: &Context) {
         .default_width(400.0)
         .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO) // Center the window
         .show(ctx, |ui| {
-            if app.pending_settings.is_none() {
+            // Taken out while drawing (so the categories can also use other app state), put back below
+            let Some(mut pending) = app.pending_settings.take() else {
                 error!("Settings window drawn without pending state initialized!");
                 ui.colored_label(
                     ui.visuals().error_fg_color,
                     "Internal error: State not initialized.",
                 );
                 return;
-            }
-            let pending = app.pending_settings.as_mut().unwrap();
+            };
 
             ui.heading("Runtime Settings");
             ui.label("These settings override .env/environment variables and are saved persistently.");
@@ -55,47 +93,47 @@ pub fn draw_settings_window(app: &mut OllamaPullerApp, ctx: &Context) {
             } else {
                 ui.label("Config file path not found.");
             }
+            if app.config_in_memory_only {
+                ui.colored_label(
+                    ui.visuals().warn_fg_color,
+                    "Config file is not writable: changes only last until the app is closed.",
+                );
+            }
             ui.separator();
 
-            // Grid layout for settings fields
-            Grid::new("settings_grid")
-                .num_columns(2)
-                .spacing([40.0, 4.0]) // Horizontal and vertical spacing
-                .striped(true) // Alternate row background colors
+            // Collapsible sections, one two-column grid per category
+            ScrollArea::vertical()
+                .max_height(ctx.screen_rect().height() * 0.7)
                 .show(ui, |ui| {
-                    // Ollama Host setting
-                    ui.label("Ollama Host:");
-                    // Edit the temporary pending state
-                    ui.text_edit_singleline(&mut pending.ollama_host);
-                    ui.end_row();
-
-                    // Log Level setting
-                    ui.label("Log Level:");
-                    // Use ComboBox for selecting log level
-                    ComboBox::from_label("") // No label needed next to the combo box
-                        .selected_text(&pending.log_level) // Show current selection
-                        .show_ui(ui, |ui| {
-                            // Iterate through possible log level strings
-                            for level in ["TRACE", "DEBUG", "INFO", "WARN", "ERROR"] {
-                                // Allow selecting a value, updates pending.log_level
-                                ui.selectable_value(
-                                    &mut pending.log_level,
-                                    level.to_string(),
-                                    level,
-                                );
-                            }
-                        });
-                    ui.end_row();
-                    ui.label("Timezone (IANA):");
-                    let timezone_edit = TextEdit::singleline(&mut pending.tz)
-                        .hint_text("e.g., Europe/Vienna, UTC");
-                    ui.add(timezone_edit);
-                    ui.end_row();
+                    for category in SettingsCategory::ALL {
+                        CollapsingHeader::new(category.title())
+                            .id_salt(("settings_category", category.title()))
+                            .default_open(category == SettingsCategory::General)
+                            .show(ui, |ui| {
+                                Grid::new(("settings_grid", category.title()))
+                                    .num_columns(2)
+                                    .spacing([40.0, 4.0]) // Horizontal and vertical spacing
+                                    .striped(true) // Alternate row background colors
+                                    .min_col_width(140.0) // Line up the value columns of all sections
+                                    .show(ui, |ui| draw_category_rows(ui, category, &mut pending, app));
+                            });
+                    }
                 });
             ui.separator();
             ui.horizontal(|ui| {
-                if ui.button("Save & Close").clicked() {
-                    if Tz::from_str(&pending.tz).is_err() {
+                if ui.button("Save & Close").on_hover_text("Enter").clicked() || enter_pressed {
+                    let models_dir_error = if pending.models_dir.is_empty() {
+                        None
+                    } else {
+                        config::validate_models_dir(&pending.models_dir).err()
+                    };
+                    if let Some(e) = models_dir_error {
+                        let error_msg = format!("Invalid model store override: {}. Settings not saved.", e);
+                        error!("{}", error_msg);
+                        let _ = app
+                            .task_update_sender
+                            .send(UpdateMessage::Log(format!("ERROR: {}", error_msg)));
+                    } else if Tz::from_str(&pending.tz).is_err() {
                         let error_msg = format!(
                             "Invalid Timezone format: '{}'. Please use IANA format (e.g., 'Europe/Vienna', 'UTC'). Settings not saved.",
                             pending.tz 
@@ -108,22 +146,34 @@ pub fn draw_settings_window(app: &mut OllamaPullerApp, ctx: &Context) {
                         save_and_close_clicked = true;
                     }
                 }
-                if ui.button("Cancel").clicked() {
+                if ui.button("Cancel").on_hover_text("Esc").clicked() || escape_pressed {
                     cancel_settings_clicked = true;
                 }
             });
             ui.separator();
             ui.label("Note: Log Level and Timezone changes may require an application restart for the log timestamp format to fully update.");
+            app.pending_settings = Some(pending);
         });
 
     if save_and_close_clicked {
         if let Some(saved_settings) = app.pending_settings.take() {
-            app.settings = saved_settings;
-            app.save_settings();
-            info!("Settings updated and saved.");
-            let _ = app
-                .task_update_sender
-                .send(UpdateMessage::Log("INFO: Settings updated and saved.".to_string()));
+            if saved_settings == app.settings {
+                // Nothing was changed in the window, so there's nothing to write
+                info!("No settings changes to save.");
+                let _ = app
+                    .task_update_sender
+                    .send(UpdateMessage::Log("INFO: No settings changes to save.".to_string()));
+            } else {
+                config::set_gui_log_level(&saved_settings.gui_log_level);
+                app.settings = saved_settings;
+                app.save_settings();
+                app.manage_view_cache_dirty = true; // Display settings (digest, timezone) may have changed
+                app.update_model_store_space();
+                info!("Settings updated and saved.");
+                let _ = app
+                    .task_update_sender
+                    .send(UpdateMessage::Log("INFO: Settings updated and saved.".to_string()));
+            }
         } else {
             // This case should ideally not happen if the window was open
             error!("Save clicked but pending_settings was None!");
@@ -158,3 +208,431 @@ pub fn draw_settings_window(app: &mut OllamaPullerApp, ctx: &Context) {
         app.pending_settings = None;
     }
 }
+
+// Draws the rows of one settings category into its two-column grid.
+//
+// # Arguments
+//
+// * ui - Mutable reference to the egui UI context of the category's grid.
+// * category - The category to draw.
+// * pending - The settings being edited (saved on "Save & Close").
+// * app - Mutable reference to the main application state (for the window's helper state).
+fn draw_category_rows(ui: &mut egui::Ui, category: SettingsCategory, pending: &mut AppSettings, app: &mut OllamaPullerApp) {
+    match category {
+        SettingsCategory::General => {
+            // Ollama Host setting
+            ui.label("Ollama Host:");
+            // Edit the temporary pending state
+            ui.text_edit_singleline(&mut pending.ollama_host);
+            ui.end_row();
+
+            // Log Level setting
+            ui.label("Log Level:");
+            // Use ComboBox for selecting log level
+            ComboBox::from_label("") // No label needed next to the combo box
+                .selected_text(&pending.log_level) // Show current selection
+                .show_ui(ui, |ui| {
+                    // Iterate through possible log level strings
+                    for level in ["TRACE", "DEBUG", "INFO", "WARN", "ERROR"] {
+                        // Allow selecting a value, updates pending.log_level
+                        ui.selectable_value(
+                            &mut pending.log_level,
+                            level.to_string(),
+                            level,
+                        );
+                    }
+                });
+            ui.end_row();
+
+            ui.label("Log Panel Level:");
+            ComboBox::from_id_salt("gui_log_level")
+                .selected_text(&pending.gui_log_level)
+                .show_ui(ui, |ui| {
+                    for level in ["TRACE", "DEBUG", "INFO", "WARN", "ERROR"] {
+                        ui.selectable_value(&mut pending.gui_log_level, level.to_string(), level);
+                    }
+                })
+                .response
+                .on_hover_text("Most verbose messages shown in the Logs panel. Independent of Log Level, which only applies to the terminal/log output (the panel can't show logger messages that Log Level filters out).");
+            ui.end_row();
+            ui.label("Log Panel:");
+            ui.checkbox(&mut pending.expand_logs_on_error, "Expand on errors")
+                .on_hover_text("Open the collapsed Logs panel and scroll to the newest lines when an operation fails.");
+            ui.end_row();
+            ui.label("Timezone (IANA):");
+            let timezone_edit = TextEdit::singleline(&mut pending.tz)
+                .hint_text("e.g., Europe/Vienna, UTC");
+            ui.add(timezone_edit);
+            ui.end_row();
+
+            ui.label("Updates:");
+            ui.checkbox(&mut pending.check_for_updates, "Check for LlamaLift updates")
+                .on_hover_text("Ask GitHub at most once a day whether a newer release exists, and show a link to it. Nothing is downloaded or installed.");
+            ui.end_row();
+        }
+        SettingsCategory::Downloads => {
+            ui.label("Default Namespace:");
+            ui.add(
+                TextEdit::singleline(&mut pending.default_namespace)
+                    .hint_text("optional, e.g. myteam"),
+            )
+            .on_hover_text("Prepended as 'namespace/name' to entered models that have no namespace of their own.");
+            ui.end_row();
+
+            // Short names typed in the Download view, expanded before pulling
+            ui.label("Model Aliases:");
+            ui.vertical(|ui| {
+                let mut alias_to_remove = None;
+                for (alias, target) in &pending.model_aliases {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{} → {}", alias, target));
+                        if ui.small_button("➖").on_hover_text("Remove this alias").clicked() {
+                            alias_to_remove = Some(alias.clone());
+                        }
+                    });
+                }
+                if let Some(alias) = alias_to_remove {
+                    pending.model_aliases.remove(&alias);
+                }
+                ui.horizontal(|ui| {
+                    ui.add(TextEdit::singleline(&mut app.new_alias_name).hint_text("alias, e.g. code").desired_width(90.0));
+                    ui.label("→");
+                    ui.add(
+                        TextEdit::singleline(&mut app.new_alias_target)
+                            .hint_text("e.g. codellama:13b-instruct")
+                            .desired_width(180.0),
+                    );
+                    let alias = app.new_alias_name.trim().to_string();
+                    let target = app.new_alias_target.trim().to_string();
+                    let validation = utils::validate_model_alias(&alias)
+                        .and_then(|_| utils::validate_model_identifier(&target));
+                    let add_clicked = ui
+                        .add_enabled(!target.is_empty() && validation.is_ok(), egui::Button::new("➕").small())
+                        .on_hover_text("Add the alias (replaces an existing one with the same name)")
+                        .clicked();
+                    if add_clicked {
+                        info!("Added model alias '{}' -> '{}'.", alias, target);
+                        pending.model_aliases.insert(alias, target);
+                        app.new_alias_name.clear();
+                        app.new_alias_target.clear();
+                    } else if let Err(e) = validation {
+                        // Only complain once both fields have something in them
+                        if !alias.is_empty() && !target.is_empty() {
+                            ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", e));
+                        }
+                    }
+                });
+            });
+            ui.end_row();
+
+            ui.label("Large Batches:");
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut pending.confirm_batch_size, "Confirm more than")
+                    .on_hover_text("Ask before starting a batch with more models than this, showing the count and estimated size.");
+                ui.add_enabled(
+                    pending.confirm_batch_size,
+                    egui::DragValue::new(&mut pending.batch_size_confirm_threshold)
+                        .range(1..=MAX_MODEL_INPUTS)
+                        .suffix(" models"),
+                );
+            });
+            ui.end_row();
+
+            ui.label("Large Downloads:");
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut pending.confirm_large_pulls, "Confirm above")
+                    .on_hover_text("Ask before pulling models that LlamaLift's bundled catalog lists as larger than this. Sizes of other models aren't known before pulling.");
+                ui.add_enabled(
+                    pending.confirm_large_pulls,
+                    egui::DragValue::new(&mut pending.large_pull_threshold_gib)
+                        .range(1..=1000)
+                        .suffix(" GiB"),
+                );
+            });
+            ui.end_row();
+
+            ui.label("Disk Space:");
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut pending.abort_on_low_disk, "Keep free")
+                    .on_hover_text("Don't start a pull that would leave less than this free in the local model store (for catalog-listed models), and stop a running pull when free space drops below it. Only works when Ollama runs on this machine.");
+                ui.add_enabled(
+                    pending.abort_on_low_disk,
+                    egui::DragValue::new(&mut pending.min_free_disk_gib)
+                        .range(0..=1000)
+                        .suffix(" GiB"),
+                );
+            });
+            ui.end_row();
+
+            ui.label("Finished Batches:");
+            ui.checkbox(&mut pending.auto_clear_batch_progress, "Hide progress after a few seconds")
+                .on_hover_text("Return the Download view to idle shortly after a successful batch. Errors stay visible.");
+            ui.end_row();
+
+            ui.label("");
+            let sound_available = cfg!(feature = "sound");
+            ui.add_enabled(
+                sound_available,
+                egui::Checkbox::new(&mut pending.play_sound_on_completion, "Play a chime when done"),
+            )
+            .on_hover_text("Rising tones on success, falling tones on failure.")
+            .on_disabled_hover_text("This build of LlamaLift was compiled without the 'sound' feature.");
+            ui.end_row();
+
+            ui.label("Pull Progress:");
+            ui.checkbox(&mut pending.stream_pulls, "Stream live progress")
+                .on_hover_text("Turn off if a proxy breaks streamed (chunked) responses. Pulls then show no live progress until each model completes.");
+            ui.end_row();
+        }
+        SettingsCategory::ManageModels => {
+            ui.label("Models Table:");
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut pending.table_striped, "Striped rows");
+                ui.checkbox(&mut pending.table_gridlines, "Column gridlines")
+                    .on_hover_text("Vertical lines between columns, for easier reading of wide tables.");
+                ui.checkbox(&mut pending.table_wrap_text, "Wrap text")
+                    .on_hover_text("Wrap long values (e.g. Families) within the column width instead of cutting them off. Rows grow to fit.");
+                ui.checkbox(&mut pending.freeze_name_column, "Freeze Name column")
+                    .on_hover_text("Keep the Name column visible while scrolling the other columns horizontally. Not used in the grouped view.");
+            });
+            ui.end_row();
+
+            ui.label("Table Colors:");
+            ui.horizontal(|ui| {
+                let default_header = ui.visuals().widgets.noninteractive.bg_fill;
+                let default_stripe = ui.visuals().faint_bg_color;
+                draw_optional_color(ui, "Header", &mut pending.table_header_color, default_header);
+                draw_optional_color(ui, "Stripes", &mut pending.table_stripe_color, default_stripe);
+            });
+            ui.end_row();
+
+            ui.label("Digest Column:");
+            ui.horizontal(|ui| {
+                ComboBox::from_id_salt("digest_display_chars")
+                    .selected_text(match pending.digest_display_chars {
+                        0 => "Full".to_string(),
+                        n => format!("{} chars", n),
+                    })
+                    .show_ui(ui, |ui| {
+                        for (chars, label) in [(8, "8 chars"), (12, "12 chars"), (0, "Full")] {
+                            ui.selectable_value(&mut pending.digest_display_chars, chars, label);
+                        }
+                    });
+                ui.checkbox(&mut pending.digest_strip_prefix, "Strip 'sha256:'");
+                ui.checkbox(&mut pending.digest_disambiguate, "Lengthen to disambiguate")
+                    .on_hover_text("Show more characters when two different models' shortened digests would look the same.");
+            });
+            ui.end_row();
+
+            ui.label("Size Column:");
+            ui.checkbox(&mut pending.dash_for_unknown_size, "Show '—' for models without a size")
+                .on_hover_text("Some custom/blob models report a size of 0. Show '—' instead of '0 B' for them.");
+            ui.end_row();
+
+            ui.label("Deleting Models:");
+            ui.checkbox(&mut pending.confirm_deletes, "Ask for confirmation")
+                .on_hover_text("Turn off to delete models with a single click, e.g. for a personal local Ollama. Deleted models can't be restored, only pulled again. A badge in the top bar shows while this is off.");
+            ui.end_row();
+
+            ui.label("Model Details:");
+            ui.checkbox(&mut pending.prefetch_model_details, "Prefetch for visible rows")
+                .on_hover_text("Fetch /api/show details of the rows you scroll to in the background, so they are ready when needed. A little extra traffic; paused while an operation runs.");
+            ui.end_row();
+
+            ui.label("Model List:");
+            ui.checkbox(&mut pending.per_model_refresh, "Update only the affected models")
+                .on_hover_text("After a delete, remove just that entry instead of reloading the list; after a pull, merge just the pulled models' entries. Less flicker and traffic with large inventories. Falls back to a full refresh when a model can't be matched.");
+            ui.end_row();
+
+            ui.label("");
+            ui.checkbox(&mut pending.refresh_after_pull, "Refresh after pulling")
+                .on_hover_text("Update the model list when a batch pull finishes (unless every model failed), so new models show up without clicking Refresh. Deletes always update the list.");
+            ui.end_row();
+
+            ui.label("Filters:");
+            ui.checkbox(&mut pending.remember_filters, "Remember filters across restarts")
+                .on_hover_text("Restore the Manage Models filters (e.g. 'Only loaded') on the next start. The sort order is always kept. Filters survive list refreshes either way.");
+            ui.end_row();
+
+            ui.label("Model Updates:");
+            ui.checkbox(&mut pending.check_model_updates, "Check the registry daily")
+                .on_hover_text("Ask registry.ollama.ai at most once a day whether newer versions of your models' tags exist, and mark them with ⬆. Only the manifest digests are compared, nothing is downloaded. The \"⬆ Check Updates\" button works either way.");
+            ui.end_row();
+        }
+        SettingsCategory::Network => {
+            ui.label("Retries:");
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::DragValue::new(&mut pending.max_retries)
+                        .range(0..=MAX_RETRIES_LIMIT)
+                        .prefix("max "),
+                )
+                .on_hover_text("Retries for requests failing with a connection error, timeout or 502/503/504. 0 disables retries.");
+                ui.add_enabled(
+                    pending.max_retries > 0,
+                    egui::DragValue::new(&mut pending.base_backoff_ms)
+                        .range(BACKOFF_MS_RANGE)
+                        .speed(10)
+                        .prefix("backoff ")
+                        .suffix(" ms"),
+                )
+                .on_hover_text("Delay before the first retry, doubled for each further retry.");
+            });
+            ui.end_row();
+
+            ui.label("HTTP Redirects:");
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::DragValue::new(&mut pending.max_redirects)
+                        .range(0..=30)
+                        .prefix("max "),
+                )
+                .on_hover_text("Redirects followed per request (e.g. behind a proxy). 0 disables following redirects.");
+                ui.add_enabled(
+                    pending.max_redirects > 0,
+                    egui::Checkbox::new(&mut pending.follow_pull_redirects, "Also for pulls"),
+                )
+                .on_hover_text("Streamed pulls often break across redirects, so they don't follow them by default.");
+            });
+            ui.end_row();
+
+            ui.label("Idle Pause:");
+            ui.add(
+                egui::DragValue::new(&mut pending.idle_pause_minutes)
+                    .range(0..=240)
+                    .prefix("after ")
+                    .suffix(" min"),
+            )
+            .on_hover_text("Stop polling the server for loaded models after this long without focus or input. Polling resumes (with a refresh) when the window is used again. 0 never pauses.");
+            ui.end_row();
+
+            ui.label("CLI Fallback:");
+            ui.checkbox(&mut pending.cli_list_fallback, "List models with `ollama list` when the API is unreachable")
+                .on_hover_text("For unusual local setups where the Ollama CLI reaches the server but the configured URL doesn't. Needs the ollama CLI on the PATH. The CLI only reports short digests, rounded sizes and relative modification times.");
+            ui.end_row();
+
+            ui.label("Custom Headers:");
+            ui.vertical(|ui| {
+                let mut header_to_remove = None;
+                for (index, (name, value)) in pending.custom_headers.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        // Values are often API keys, so they are only shown on hover
+                        ui.label(format!("{}: ••••••", name)).on_hover_text(value);
+                        if ui.small_button("➖").on_hover_text("Remove this header").clicked() {
+                            header_to_remove = Some(index);
+                        }
+                    });
+                }
+                if let Some(index) = header_to_remove {
+                    pending.custom_headers.remove(index);
+                }
+                ui.horizontal(|ui| {
+                    ui.add(TextEdit::singleline(&mut app.new_header_name).hint_text("e.g. X-Api-Key").desired_width(110.0));
+                    ui.label(":");
+                    ui.add(TextEdit::singleline(&mut app.new_header_value).hint_text("value").password(true).desired_width(160.0));
+                    let name = app.new_header_name.trim().to_string();
+                    let value = app.new_header_value.trim().to_string();
+                    let validation = utils::validate_custom_header(&name, &value);
+                    let add_clicked = ui
+                        .add_enabled(validation.is_ok(), egui::Button::new("➕").small())
+                        .on_hover_text("Add the header to every request to the Ollama server (replaces one with the same name)")
+                        .clicked();
+                    if add_clicked {
+                        info!("Added custom header '{}'.", name);
+                        pending.custom_headers.retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+                        pending.custom_headers.push((name, value));
+                        app.new_header_name.clear();
+                        app.new_header_value.clear();
+                    } else if let Err(e) = validation {
+                        if !name.is_empty() {
+                            ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", e));
+                        }
+                    }
+                });
+            });
+            ui.end_row();
+        }
+        SettingsCategory::Advanced => {
+            // Read-only while auto-detected, editable once overridden
+            ui.label("Model Store:");
+            ui.vertical(|ui| {
+                let mut is_overridden = !pending.models_dir.is_empty();
+                if ui
+                    .checkbox(&mut is_overridden, "Override")
+                    .on_hover_text("Where the local Ollama server keeps its models. Used for free-space reporting and File → Maintenance. Without an override, OLLAMA_MODELS or the platform default (~/.ollama/models) is used.")
+                    .changed()
+                {
+                    pending.models_dir = if is_overridden {
+                        // Start from the detected path (a space keeps the override on if there is none)
+                        app.detected_models_dir
+                            .as_ref()
+                            .map_or_else(|| " ".to_string(), |dir| dir.display().to_string())
+                    } else {
+                        String::new()
+                    };
+                }
+                if is_overridden {
+                    ui.add(
+                        TextEdit::singleline(&mut pending.models_dir)
+                            .hint_text("/path/to/.ollama/models")
+                            .desired_width(300.0),
+                    );
+                    match config::validate_models_dir(&pending.models_dir) {
+                        Ok(None) => {}
+                        Ok(Some(warning)) => {
+                            ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {}", warning));
+                        }
+                        Err(e) => {
+                            ui.colored_label(ui.visuals().error_fg_color, format!("✖ {}", e));
+                        }
+                    }
+                } else {
+                    match &app.detected_models_dir {
+                        Some(dir) => {
+                            let source = if config::models_dir_from_env(dir) { "OLLAMA_MODELS" } else { "default location" };
+                            ui.label(format!("{} ({})", dir.display(), source));
+                        }
+                        None => {
+                            ui.weak("Not found locally (Ollama may run on another machine)");
+                        }
+                    }
+                }
+            });
+            ui.end_row();
+
+            ui.label("Remote API:");
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut pending.remote_api_enabled, "Enable on 127.0.0.1, port")
+                    .on_hover_text("A small local HTTP API for scripts (see the README): GET /status, GET /models, POST /pull, POST /refresh. Only reachable from this machine.");
+                ui.add_enabled(
+                    pending.remote_api_enabled,
+                    egui::DragValue::new(&mut pending.remote_api_port).range(1024..=65535),
+                );
+            });
+            ui.end_row();
+        }
+    }
+}
+
+// Draws a checkbox enabling a custom color, and a color picker for it while enabled.
+// Enabling starts from the theme's current color.
+//
+// # Arguments
+//
+// * ui - Mutable reference to the egui UI context for drawing.
+// * label - Text of the checkbox.
+// * color - The optional custom color (None = theme default).
+// * theme_default - The theme color to start from.
+fn draw_optional_color(ui: &mut egui::Ui, label: &str, color: &mut Option<[u8; 3]>, theme_default: egui::Color32) {
+    let mut is_custom = color.is_some();
+    if ui
+        .checkbox(&mut is_custom, label)
+        .on_hover_text("Override the theme color, e.g. if it clashes with a custom theme.")
+        .changed()
+    {
+        *color = is_custom.then(|| [theme_default.r(), theme_default.g(), theme_default.b()]);
+    }
+    if let Some(rgb) = color {
+        ui.color_edit_button_srgb(rgb);
+    }
+}
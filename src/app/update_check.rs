@@ -0,0 +1,49 @@
+// src/app/update_check.rs
+// Optional check for a newer LlamaLift release on GitHub. Only compares versions and links to the
+// release page; nothing is downloaded or installed. Disabled by default (see the "check for updates" setting).
+
+use crate::app::{
+    config::{APP_NAME, SCRIPT_VERSION},
+    utils::parse_version,
+};
+use log::trace;
+use serde::Deserialize;
+
+/// GitHub API endpoint for the latest LlamaLift release.
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/unbraind/LlamaLift/releases/latest";
+
+/// Minimum time between two update checks.
+pub const UPDATE_CHECK_INTERVAL_HOURS: i64 = 24;
+
+/// The fields of the GitHub release response that are used.
+#[derive(Deserialize, Debug)]
+struct GitHubRelease {
+    tag_name: String,
+    html_url: String,
+}
+
+/// Fetches the latest release, returning its version (tag without a leading `v`) and release page URL.
+pub async fn fetch_latest_release_async() -> Result<(String, String), Box<dyn std::error::Error + Send + Sync>> {
+    // GitHub rejects API requests without a User-Agent
+    let client = reqwest::Client::builder()
+        .user_agent(format!("{}/{}", APP_NAME, SCRIPT_VERSION))
+        .build()?;
+    trace!("Sending release request to {}", LATEST_RELEASE_URL);
+
+    let res = client.get(LATEST_RELEASE_URL).send().await?;
+    let status_code = res.status();
+    if !status_code.is_success() {
+        return Err(format!("GitHub returned {}", status_code).into());
+    }
+    let release: GitHubRelease = res.json().await?;
+    Ok((release.tag_name.trim_start_matches('v').to_string(), release.html_url))
+}
+
+/// Returns true if `latest` is a newer version than the running one.
+/// Unparseable versions are never reported as newer.
+pub fn is_newer_than_current(latest: &str) -> bool {
+    match (parse_version(latest), parse_version(SCRIPT_VERSION)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
@@ -20,6 +20,9 @@ use std::io::Write;
 use std::sync::mpsc::channel;
 
 fn main() -> Result<(), eframe::Error> {
+    // --- Crash Reporting (local file only) ---
+    app::crash::install_panic_hook();
+
     // --- Logger Setup ---
     let initial_config = load_initial_config();
 
@@ -48,8 +51,10 @@ fn main() -> Result<(), eframe::Error> {
                 record.level(),
                 record.args()
             );
-            // Send INFO and lower logs to the GUI via the channel
-            if record.level() <= LevelFilter::Info {
+            // Keep the most recent lines for a potential crash report
+            app::crash::record_log_line(&log_msg);
+            // Send logs up to the log panel level (Settings) to the GUI via the channel
+            if record.level() <= app::config::gui_log_level() {
                 if let Err(e) = logger_sender.send(UpdateMessage::Log(log_msg.clone())) {
                     eprintln!("ERROR: Failed to send log message to UI thread: {}", e);
                 }
@@ -64,8 +69,18 @@ fn main() -> Result<(), eframe::Error> {
     info!("OLLAMA_HOST (Initial): {}", initial_config.ollama_host);
     info!("LOG_LEVEL (Effective Init): {}", log_level_to_init);
     info!("TZ (Effective Init): {}", logger_tz_str);
+    match &initial_config.models_dir {
+        Some(dir) => info!("Model store (Initial): {}", dir.display()),
+        None => info!("Model store (Initial): not found locally"),
+    }
     info!("---------------------------");
 
+    // --- Headless Mode (`llamalift pull ...`) ---
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if app::cli::is_headless_command(&args) {
+        std::process::exit(app::cli::run_headless(&args, app_sender, update_receiver));
+    }
+
     // --- Load Icon Data ---
     let icon = match image::load_from_memory(LOGO_BYTES) {
         Ok(image) => {